@@ -0,0 +1,94 @@
+use core::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use fixed_vectors::{SimdVector4, Vector4};
+
+
+const COUNT: usize = 1024;
+
+
+fn scalar_vectors() -> Vec<Vector4<f32>> {
+    (0..COUNT)
+        .map(|i| i as f32)
+        .map(|f| Vector4::new(f, f * 0.5, f * 0.25, f * 0.125))
+        .collect()
+}
+
+
+fn simd_vectors() -> Vec<SimdVector4> {
+    scalar_vectors()
+        .into_iter()
+        .map(SimdVector4::from)
+        .collect()
+}
+
+
+fn add(c: &mut Criterion) {
+    let scalar = scalar_vectors();
+    let simd = simd_vectors();
+    let mut group = c.benchmark_group("add");
+
+    group.bench_function("Vector4<f32>", |b| b.iter(|| {
+        black_box(&scalar).iter().fold(Vector4::from_value(0.0), |sum, vec4| sum + *vec4)
+    }));
+
+    group.bench_function("SimdVector4", |b| b.iter(|| {
+        black_box(&simd).iter().fold(SimdVector4::from_value(0.0), |sum, vec4| sum + *vec4)
+    }));
+
+    group.finish();
+}
+
+
+fn dot(c: &mut Criterion) {
+    let scalar = scalar_vectors();
+    let simd = simd_vectors();
+    let mut group = c.benchmark_group("dot");
+
+    group.bench_function("Vector4<f32>", |b| b.iter(|| {
+        black_box(&scalar).iter().map(|vec4| vec4.dot(vec4)).sum::<f32>()
+    }));
+
+    group.bench_function("SimdVector4", |b| b.iter(|| {
+        black_box(&simd).iter().map(|vec4| vec4.dot(vec4)).sum::<f32>()
+    }));
+
+    group.finish();
+}
+
+
+fn normalized(c: &mut Criterion) {
+    let scalar = scalar_vectors();
+    let simd = simd_vectors();
+    let mut group = c.benchmark_group("normalized");
+
+    group.bench_function("Vector4<f32>", |b| b.iter(|| {
+        black_box(&scalar).iter().fold(Vector4::from_value(0.0), |sum, vec4| sum + vec4.normalized())
+    }));
+
+    group.bench_function("SimdVector4", |b| b.iter(|| {
+        black_box(&simd).iter().fold(SimdVector4::from_value(0.0), |sum, vec4| sum + vec4.normalized())
+    }));
+
+    group.finish();
+}
+
+
+fn lerp(c: &mut Criterion) {
+    let scalar = scalar_vectors();
+    let simd = simd_vectors();
+    let mut group = c.benchmark_group("lerp");
+
+    group.bench_function("Vector4<f32>", |b| b.iter(|| {
+        black_box(&scalar).windows(2).fold(Vector4::from_value(0.0), |sum, pair| sum + pair[0].lerp(pair[1], 0.5))
+    }));
+
+    group.bench_function("SimdVector4", |b| b.iter(|| {
+        black_box(&simd).windows(2).fold(SimdVector4::from_value(0.0), |sum, pair| sum + pair[0].lerp(pair[1], 0.5))
+    }));
+
+    group.finish();
+}
+
+
+criterion_group!(benches, add, dot, normalized, lerp);
+criterion_main!(benches);
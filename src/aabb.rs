@@ -0,0 +1,365 @@
+use crate::{Vector2, Vector3};
+use num_traits::Num;
+
+
+/// Axis-aligned bounding box over two-dimensional space, spanning from `min` to `max` inclusively.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Aabb2, Vector2};
+/// 
+/// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+/// 
+/// assert!(aabb.contains(Vector2::new(2, 1)));
+/// assert_eq!(aabb.area(), 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Aabb2<T> {
+    pub min: Vector2<T>,
+    pub max: Vector2<T>,
+}
+
+
+/// Axis-aligned bounding box over three-dimensional space, spanning from `min` to `max` inclusively.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Aabb3, Vector3};
+/// 
+/// let aabb = Aabb3::new(Vector3::new(0, 0, 0), Vector3::new(4, 2, 1));
+/// 
+/// assert!(aabb.contains(Vector3::new(2, 1, 1)));
+/// assert_eq!(aabb.volume(), 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Aabb3<T> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+
+macro_rules! impl_aabb {
+    ( $aabb: ident, $struct: ident { $($field: ident), + } ) => {
+        impl<T> $aabb<T> {
+            /// Constructs a new bounding box spanning from `min` to `max`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+            /// 
+            /// assert_eq!(aabb.max, Vector2::new(4, 2));
+            /// ```
+            #[inline(always)]
+            pub const fn new(min: $struct<T>, max: $struct<T>) -> Self {
+                Self { min, max }
+            }
+        }
+
+        impl<T: PartialOrd + Copy> $aabb<T> {
+            /// Constructs the smallest bounding box containing every vector within `points`,
+            /// or [`None`] if `points` is empty.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::from_points(&[Vector2::new(3, -1), Vector2::new(0, 2), Vector2::new(1, 1)]);
+            /// 
+            /// assert_eq!(aabb, Some(Aabb2::new(Vector2::new(0, -1), Vector2::new(3, 2))));
+            /// assert_eq!(Aabb2::<i32>::from_points(&[]), None);
+            /// ```
+            pub fn from_points(points: &[$struct<T>]) -> Option<Self> {
+                let (first, rest) = points.split_first()?;
+
+                Some(rest.iter().fold(Self::new(*first, *first), |aabb, point| aabb.expand_to_include(*point)))
+            }
+
+            /// Returns `true` if `point` lies within or on the boundary of the bounding box.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+            /// 
+            /// assert!(aabb.contains(Vector2::new(4, 0)));
+            /// assert!(!aabb.contains(Vector2::new(5, 0)));
+            /// ```
+            #[inline]
+            pub fn contains(&self, point: $struct<T>) -> bool {
+                $( point.$field >= self.min.$field && point.$field <= self.max.$field ) && +
+            }
+
+            /// Returns `true` if `other` lies entirely within the bounding box.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+            /// 
+            /// assert!(aabb.contains_aabb(&Aabb2::new(Vector2::new(1, 1), Vector2::new(2, 2))));
+            /// assert!(!aabb.contains_aabb(&Aabb2::new(Vector2::new(1, 1), Vector2::new(2, 3))));
+            /// ```
+            #[inline]
+            pub fn contains_aabb(&self, other: &Self) -> bool {
+                self.contains(other.min) && self.contains(other.max)
+            }
+
+            /// Returns `true` if the two bounding boxes overlap or touch.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+            /// 
+            /// assert!(aabb.intersects(&Aabb2::new(Vector2::new(4, 2), Vector2::new(6, 6))));
+            /// assert!(!aabb.intersects(&Aabb2::new(Vector2::new(5, 0), Vector2::new(6, 6))));
+            /// ```
+            #[inline]
+            pub fn intersects(&self, other: &Self) -> bool {
+                $( self.min.$field <= other.max.$field && self.max.$field >= other.min.$field ) && +
+            }
+
+            /// Returns the overlapping region of the two bounding boxes, or [`None`] if they do not intersect.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+            /// 
+            /// let other = Aabb2::new(Vector2::new(2, 1), Vector2::new(6, 6));
+            /// 
+            /// assert_eq!(aabb.intersection(&other), Some(Aabb2::new(Vector2::new(2, 1), Vector2::new(4, 2))));
+            /// ```
+            #[inline]
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                if !self.intersects(other) {
+                    return None;
+                }
+
+                Some(Self::new(self.min.max_by_component(other.min), self.max.min_by_component(other.max)))
+            }
+
+            /// Returns the smallest bounding box containing both bounding boxes.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+            /// 
+            /// let other = Aabb2::new(Vector2::new(2, -1), Vector2::new(6, 1));
+            /// 
+            /// assert_eq!(aabb.union(&other), Aabb2::new(Vector2::new(0, -1), Vector2::new(6, 2)));
+            /// ```
+            #[inline]
+            pub fn union(&self, other: &Self) -> Self {
+                Self::new(self.min.min_by_component(other.min), self.max.max_by_component(other.max))
+            }
+
+            /// Consumes the bounding box and returns the smallest bounding box also containing `point`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2)).expand_to_include(Vector2::new(-1, 5));
+            /// 
+            /// assert_eq!(aabb, Aabb2::new(Vector2::new(-1, 0), Vector2::new(4, 5)));
+            /// ```
+            #[inline]
+            pub fn expand_to_include(self, point: $struct<T>) -> Self {
+                Self::new(self.min.min_by_component(point), self.max.max_by_component(point))
+            }
+
+            /// Returns the point within the bounding box closest to `point`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+            /// 
+            /// assert_eq!(aabb.closest_point(Vector2::new(6, -3)), Vector2::new(4, 0));
+            /// assert_eq!(aabb.closest_point(Vector2::new(1, 1)), Vector2::new(1, 1));
+            /// ```
+            #[inline]
+            pub fn closest_point(&self, point: $struct<T>) -> $struct<T> {
+                point.clamp_by_component(self.min, self.max)
+            }
+        }
+
+        impl<T: Num + PartialOrd + Copy> $aabb<T> {
+            /// Consumes the bounding box and returns it grown by `amount` on every side.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2)).expand(1);
+            /// 
+            /// assert_eq!(aabb, Aabb2::new(Vector2::new(-1, -1), Vector2::new(5, 3)));
+            /// ```
+            #[inline]
+            pub fn expand(self, amount: T) -> Self {
+                Self::new(
+                    $struct { $( $field: self.min.$field - amount ), + },
+                    $struct { $( $field: self.max.$field + amount ), + },
+                )
+            }
+
+            /// Returns the extent of the bounding box along each axis.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(1, 0), Vector2::new(4, 2));
+            /// 
+            /// assert_eq!(aabb.size(), Vector2::new(3, 2));
+            /// ```
+            #[inline]
+            pub fn size(&self) -> $struct<T> {
+                $struct {
+                    $( $field: self.max.$field - self.min.$field ), +
+                }
+            }
+
+            /// Returns the center of the bounding box.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Aabb2, Vector2};
+            /// 
+            /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+            /// 
+            /// assert_eq!(aabb.center(), Vector2::new(2, 1));
+            /// ```
+            #[inline]
+            pub fn center(&self) -> $struct<T> {
+                let two = T::one() + T::one();
+
+                $struct {
+                    $( $field: (self.min.$field + self.max.$field) / two ), +
+                }
+            }
+        }
+    };
+}
+
+
+impl_aabb!(Aabb2, Vector2 { x, y });
+impl_aabb!(Aabb3, Vector3 { x, y, z });
+
+
+impl<T: Num + PartialOrd + Copy> Aabb2<T> {
+    /// Returns the area covered by the bounding box.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Aabb2, Vector2};
+    /// 
+    /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+    /// 
+    /// assert_eq!(aabb.area(), 8);
+    /// ```
+    #[inline]
+    pub fn area(&self) -> T {
+        let size = self.size();
+        size.x * size.y
+    }
+}
+
+
+impl<T: Copy> Aabb2<T> {
+    /// Returns the four corners of the bounding box, ordered counter-clockwise starting at `min`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Aabb2, Vector2};
+    /// 
+    /// let aabb = Aabb2::new(Vector2::new(0, 0), Vector2::new(4, 2));
+    /// 
+    /// assert_eq!(aabb.corners(), [Vector2::new(0, 0), Vector2::new(4, 0), Vector2::new(4, 2), Vector2::new(0, 2)]);
+    /// ```
+    #[inline]
+    pub fn corners(&self) -> [Vector2<T>; 4] {
+        let (min, max) = (self.min, self.max);
+
+        [
+            Vector2::new(min.x, min.y),
+            Vector2::new(max.x, min.y),
+            Vector2::new(max.x, max.y),
+            Vector2::new(min.x, max.y),
+        ]
+    }
+}
+
+
+impl<T: Num + PartialOrd + Copy> Aabb3<T> {
+    /// Returns the volume enclosed by the bounding box.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Aabb3, Vector3};
+    /// 
+    /// let aabb = Aabb3::new(Vector3::new(0, 0, 0), Vector3::new(4, 2, 3));
+    /// 
+    /// assert_eq!(aabb.volume(), 24);
+    /// ```
+    #[inline]
+    pub fn volume(&self) -> T {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+}
+
+
+impl<T: Copy> Aabb3<T> {
+    /// Returns the eight corners of the bounding box, with bit `0`, `1` and `2` of each index
+    /// selecting `max` over `min` on the `x`, `y` and `z` axis respectively.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Aabb3, Vector3};
+    /// 
+    /// let corners = Aabb3::new(Vector3::new(0, 0, 0), Vector3::new(4, 2, 3)).corners();
+    /// 
+    /// assert_eq!(corners[0], Vector3::new(0, 0, 0));
+    /// assert_eq!(corners[0b101], Vector3::new(4, 0, 3));
+    /// assert_eq!(corners[7], Vector3::new(4, 2, 3));
+    /// ```
+    #[inline]
+    pub fn corners(&self) -> [Vector3<T>; 8] {
+        let (min, max) = (self.min, self.max);
+
+        core::array::from_fn(|index| Vector3::new(
+            if index & 0b001 == 0 { min.x } else { max.x },
+            if index & 0b010 == 0 { min.y } else { max.y },
+            if index & 0b100 == 0 { min.z } else { max.z },
+        ))
+    }
+}
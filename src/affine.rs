@@ -0,0 +1,702 @@
+use crate::{Vector2, Vector3};
+use crate::macros::floating::FloatingPoint;
+use num_traits::float::FloatCore;
+
+
+/// Affine transformation in two-dimensional space, made of a linear part stored as the columns
+/// `x_axis` and `y_axis`, followed by a `translation`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Affine2, Vector2};
+/// 
+/// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0))
+///     * Affine2::from_scale(Vector2::new(2.0, 2.0));
+/// 
+/// assert_eq!(affine.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(3.0, 4.0));
+/// assert_eq!(affine.transform_vector(Vector2::new(1.0, 1.0)), Vector2::new(2.0, 2.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Affine2<T> {
+    pub x_axis: Vector2<T>,
+    pub y_axis: Vector2<T>,
+    pub translation: Vector2<T>,
+}
+
+
+/// Affine transformation in three-dimensional space, made of a linear part stored as the columns
+/// `x_axis`, `y_axis` and `z_axis`, followed by a `translation`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Affine3, Vector3};
+/// 
+/// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0))
+///     * Affine3::from_scale(Vector3::new(2.0, 2.0, 2.0));
+/// 
+/// assert_eq!(affine.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(3.0, 4.0, 5.0));
+/// assert_eq!(affine.transform_vector(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 2.0, 2.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Affine3<T> {
+    pub x_axis: Vector3<T>,
+    pub y_axis: Vector3<T>,
+    pub z_axis: Vector3<T>,
+    pub translation: Vector3<T>,
+}
+
+
+impl<T> Affine2<T> {
+    /// Constructs a new transformation from the columns of its linear part and its translation.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::new(Vector2::new(2.0, 0.0), Vector2::new(0.0, 2.0), Vector2::new(1.0, 1.0));
+    /// 
+    /// assert_eq!(affine.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(3.0, 3.0));
+    /// ```
+    #[inline(always)]
+    pub const fn new(x_axis: Vector2<T>, y_axis: Vector2<T>, translation: Vector2<T>) -> Self {
+        Self { x_axis, y_axis, translation }
+    }
+}
+
+
+impl<T> Affine3<T> {
+    /// Constructs a new transformation from the columns of its linear part and its translation.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::new(
+    ///     Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, 2.0), Vector3::new(1.0, 1.0, 1.0),
+    /// );
+    /// 
+    /// assert_eq!(affine.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(3.0, 3.0, 3.0));
+    /// ```
+    #[inline(always)]
+    pub const fn new(x_axis: Vector3<T>, y_axis: Vector3<T>, z_axis: Vector3<T>, translation: Vector3<T>) -> Self {
+        Self { x_axis, y_axis, z_axis, translation }
+    }
+}
+
+
+impl<T: FloatCore> Affine2<T> {
+    /// Returns the transformation which leaves every point unchanged.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::<f32>::identity();
+    /// 
+    /// assert_eq!(affine.transform_point(Vector2::new(1.0, 2.0)), Vector2::new(1.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_translation(Vector2::new(T::zero(), T::zero()))
+    }
+
+    /// Constructs a transformation which moves points by `translation`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0));
+    /// 
+    /// assert_eq!(affine.transform_point(Vector2::new(3.0, 4.0)), Vector2::new(4.0, 6.0));
+    /// ```
+    #[inline]
+    pub fn from_translation(translation: Vector2<T>) -> Self {
+        Self::new(
+            Vector2::new(T::one(), T::zero()),
+            Vector2::new(T::zero(), T::one()),
+            translation,
+        )
+    }
+
+    /// Constructs a transformation which scales points along each axis by `scale`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_scale(Vector2::new(2.0, 3.0));
+    /// 
+    /// assert_eq!(affine.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn from_scale(scale: Vector2<T>) -> Self {
+        Self::new(
+            Vector2::new(scale.x, T::zero()),
+            Vector2::new(T::zero(), scale.y),
+            Vector2::new(T::zero(), T::zero()),
+        )
+    }
+
+    /// Returns the determinant of the linear part, which is zero when the transformation cannot be inverted.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_scale(Vector2::new(2.0, 3.0));
+    /// 
+    /// assert_eq!(affine.determinant(), 6.0);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.x_axis.perp_dot(&self.y_axis)
+    }
+
+    /// Transforms `point`, applying the linear part and the translation.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0));
+    /// 
+    /// assert_eq!(affine.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: Vector2<T>) -> Vector2<T> {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Transforms `vector`, applying only the linear part so directions and offsets are not translated.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0));
+    /// 
+    /// assert_eq!(affine.transform_vector(Vector2::new(1.0, 1.0)), Vector2::new(1.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector2<T>) -> Vector2<T> {
+        (self.x_axis * vector.x) + (self.y_axis * vector.y)
+    }
+
+    /// Returns the transformation undoing this one, or [`None`] if its determinant is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_translation(Vector2::new(1.0, 2.0)) * Affine2::from_scale(Vector2::new(2.0, 4.0));
+    /// 
+    /// let inverse = affine.inverse().unwrap();
+    /// 
+    /// assert_eq!(inverse.transform_point(Vector2::new(3.0, 6.0)), Vector2::new(1.0, 1.0));
+    /// assert_eq!(Affine2::from_scale(Vector2::new(0.0, 1.0)).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant == T::zero() {
+            return None;
+        }
+
+        let x_axis = Vector2::new(self.y_axis.y, -self.x_axis.y) / determinant;
+        let y_axis = Vector2::new(-self.y_axis.x, self.x_axis.x) / determinant;
+        let linear = Self::new(x_axis, y_axis, Vector2::new(T::zero(), T::zero()));
+
+        Some(Self::new(x_axis, y_axis, -linear.transform_vector(self.translation)))
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Affine2<T> {
+    /// Constructs a transformation which rotates points counter-clockwise by `angle` radians.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_rotation(core::f64::consts::FRAC_PI_2);
+    /// let rotated = affine.transform_point(Vector2::new(1.0, 0.0));
+    /// 
+    /// assert!((rotated - Vector2::new(0.0, 1.0)).length() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn from_rotation(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+
+        Self::new(
+            Vector2::new(cos, sin),
+            Vector2::new(-sin, cos),
+            Vector2::new(T::zero(), T::zero()),
+        )
+    }
+
+    /// Constructs a transformation which scales, then rotates by `angle` radians, then translates points.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_scale_rotation_translation(
+    ///     Vector2::new(2.0, 2.0), core::f64::consts::FRAC_PI_2, Vector2::new(1.0, 0.0)
+    /// );
+    /// 
+    /// let transformed = affine.transform_point(Vector2::new(1.0, 0.0));
+    /// 
+    /// assert!((transformed - Vector2::new(1.0, 2.0)).length() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn from_scale_rotation_translation(scale: Vector2<T>, angle: T, translation: Vector2<T>) -> Self {
+        let rotation = Self::from_rotation(angle);
+        Self::new(rotation.x_axis * scale.x, rotation.y_axis * scale.y, translation)
+    }
+
+    /// Decomposes the transformation into the scale, rotation angle in radians and translation
+    /// which [`from_scale_rotation_translation`](Self::from_scale_rotation_translation) would build it from.
+    /// A reflection is represented by a negative `x` scale.
+    /// 
+    /// If the `x` axis has zero length, its scale is zero and the returned angle is `NaN`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine2, Vector2};
+    /// 
+    /// let affine = Affine2::from_scale_rotation_translation(Vector2::new(2.0, 3.0), 0.5_f64, Vector2::new(1.0, 4.0));
+    /// let (scale, angle, translation) = affine.to_scale_rotation_translation();
+    /// 
+    /// assert!((scale - Vector2::new(2.0, 3.0)).length() < 1e-9);
+    /// assert!((angle - 0.5).abs() < 1e-9);
+    /// assert_eq!(translation, Vector2::new(1.0, 4.0));
+    /// ```
+    pub fn to_scale_rotation_translation(&self) -> (Vector2<T>, T, Vector2<T>) {
+        let scale = Vector2::new(
+            self.x_axis.length() * self.determinant().signum(),
+            self.y_axis.length(),
+        );
+
+        let angle = (self.x_axis.y / scale.x).atan2(self.x_axis.x / scale.x);
+
+        (scale, angle, self.translation)
+    }
+}
+
+
+impl<T: FloatCore> Default for Affine2<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+
+impl<T: FloatCore> core::ops::Mul for Affine2<T> {
+    type Output = Self;
+
+    /// Composes the two transformations, producing one which applies `other` first and then `self`.
+    fn mul(self, other: Self) -> Self::Output {
+        Self::new(
+            self.transform_vector(other.x_axis),
+            self.transform_vector(other.y_axis),
+            self.transform_point(other.translation),
+        )
+    }
+}
+
+
+impl<T: FloatCore> core::ops::MulAssign for Affine2<T> {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+
+impl<T: FloatCore> Affine3<T> {
+    /// Returns the transformation which leaves every point unchanged.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::<f32>::identity();
+    /// 
+    /// assert_eq!(affine.transform_point(Vector3::new(1.0, 2.0, 3.0)), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        Self::from_translation(Vector3::new(T::zero(), T::zero(), T::zero()))
+    }
+
+    /// Constructs a transformation which moves points by `translation`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// 
+    /// assert_eq!(affine.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn from_translation(translation: Vector3<T>) -> Self {
+        Self::new(
+            Vector3::new(T::one(), T::zero(), T::zero()),
+            Vector3::new(T::zero(), T::one(), T::zero()),
+            Vector3::new(T::zero(), T::zero(), T::one()),
+            translation,
+        )
+    }
+
+    /// Constructs a transformation which scales points along each axis by `scale`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    /// 
+    /// assert_eq!(affine.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn from_scale(scale: Vector3<T>) -> Self {
+        Self::new(
+            Vector3::new(scale.x, T::zero(), T::zero()),
+            Vector3::new(T::zero(), scale.y, T::zero()),
+            Vector3::new(T::zero(), T::zero(), scale.z),
+            Vector3::new(T::zero(), T::zero(), T::zero()),
+        )
+    }
+
+    /// Returns the determinant of the linear part, which is zero when the transformation cannot be inverted.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    /// 
+    /// assert_eq!(affine.determinant(), 24.0);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.x_axis.dot(&self.y_axis.cross(&self.z_axis))
+    }
+
+    /// Transforms `point`, applying the linear part and the translation.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// 
+    /// assert_eq!(affine.transform_point(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T> {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Transforms `vector`, applying only the linear part so directions and offsets are not translated.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// 
+    /// assert_eq!(affine.transform_vector(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(1.0, 1.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        (self.x_axis * vector.x) + (self.y_axis * vector.y) + (self.z_axis * vector.z)
+    }
+
+    /// Returns the transformation undoing this one, or [`None`] if its determinant is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Affine3::from_scale(Vector3::new(2.0, 4.0, 8.0));
+    /// 
+    /// let inverse = affine.inverse().unwrap();
+    /// 
+    /// assert_eq!(inverse.transform_point(Vector3::new(3.0, 6.0, 11.0)), Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(Affine3::from_scale(Vector3::new(1.0, 0.0, 1.0)).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant == T::zero() {
+            return None;
+        }
+
+        // NOTE: The rows of the inverse are the cross products of the columns, divided by the determinant.
+        let row_x = self.y_axis.cross(&self.z_axis) / determinant;
+        let row_y = self.z_axis.cross(&self.x_axis) / determinant;
+        let row_z = self.x_axis.cross(&self.y_axis) / determinant;
+
+        let linear = Self::new(
+            Vector3::new(row_x.x, row_y.x, row_z.x),
+            Vector3::new(row_x.y, row_y.y, row_z.y),
+            Vector3::new(row_x.z, row_y.z, row_z.z),
+            Vector3::new(T::zero(), T::zero(), T::zero()),
+        );
+
+        Some(Self { translation: -linear.transform_vector(self.translation), ..linear })
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Affine3<T> {
+    /// Constructs a transformation which rotates points by `angle` radians around the `x` axis.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_rotation_x(core::f64::consts::FRAC_PI_2);
+    /// let rotated = affine.transform_point(Vector3::new(0.0, 1.0, 0.0));
+    /// 
+    /// assert!((rotated - Vector3::new(0.0, 0.0, 1.0)).length() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn from_rotation_x(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+
+        Self::new(
+            Vector3::new(T::one(), T::zero(), T::zero()),
+            Vector3::new(T::zero(), cos, sin),
+            Vector3::new(T::zero(), -sin, cos),
+            Vector3::new(T::zero(), T::zero(), T::zero()),
+        )
+    }
+
+    /// Constructs a transformation which rotates points by `angle` radians around the `y` axis.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_rotation_y(core::f64::consts::FRAC_PI_2);
+    /// let rotated = affine.transform_point(Vector3::new(0.0, 0.0, 1.0));
+    /// 
+    /// assert!((rotated - Vector3::new(1.0, 0.0, 0.0)).length() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn from_rotation_y(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+
+        Self::new(
+            Vector3::new(cos, T::zero(), -sin),
+            Vector3::new(T::zero(), T::one(), T::zero()),
+            Vector3::new(sin, T::zero(), cos),
+            Vector3::new(T::zero(), T::zero(), T::zero()),
+        )
+    }
+
+    /// Constructs a transformation which rotates points by `angle` radians around the `z` axis.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_rotation_z(core::f64::consts::FRAC_PI_2);
+    /// let rotated = affine.transform_point(Vector3::new(1.0, 0.0, 0.0));
+    /// 
+    /// assert!((rotated - Vector3::new(0.0, 1.0, 0.0)).length() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn from_rotation_z(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+
+        Self::new(
+            Vector3::new(cos, sin, T::zero()),
+            Vector3::new(-sin, cos, T::zero()),
+            Vector3::new(T::zero(), T::zero(), T::one()),
+            Vector3::new(T::zero(), T::zero(), T::zero()),
+        )
+    }
+
+    /// Constructs a transformation which rotates points by the Euler angles in `rotation`, in radians,
+    /// around the `x` axis first, then the `y` axis, then the `z` axis.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let half_pi = core::f64::consts::FRAC_PI_2;
+    /// let affine = Affine3::from_rotation(Vector3::new(half_pi, 0.0, half_pi));
+    /// let rotated = affine.transform_point(Vector3::new(0.0, 1.0, 0.0));
+    /// 
+    /// assert!((rotated - Vector3::new(0.0, 0.0, 1.0)).length() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn from_rotation(rotation: Vector3<T>) -> Self {
+        Self::from_rotation_z(rotation.z) * Self::from_rotation_y(rotation.y) * Self::from_rotation_x(rotation.x)
+    }
+
+    /// Constructs a transformation which rotates points counter-clockwise by `angle` radians around the
+    /// normalized `axis`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), core::f64::consts::FRAC_PI_2);
+    /// let rotated = affine.transform_point(Vector3::new(1.0, 0.0, 0.0));
+    /// 
+    /// assert!((rotated - Vector3::new(0.0, 1.0, 0.0)).length() < 1e-9);
+    /// ```
+    pub fn from_axis_angle(axis: Vector3<T>, angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let inverse_cos = T::one() - cos;
+        let (x, y, z) = axis.to_tuple();
+
+        Self::new(
+            Vector3::new((x * x * inverse_cos) + cos, (x * y * inverse_cos) + (z * sin), (x * z * inverse_cos) - (y * sin)),
+            Vector3::new((x * y * inverse_cos) - (z * sin), (y * y * inverse_cos) + cos, (y * z * inverse_cos) + (x * sin)),
+            Vector3::new((x * z * inverse_cos) + (y * sin), (y * z * inverse_cos) - (x * sin), (z * z * inverse_cos) + cos),
+            Vector3::new(T::zero(), T::zero(), T::zero()),
+        )
+    }
+
+    /// Constructs a transformation which scales, then rotates by the Euler angles in `rotation`
+    /// as in [`from_rotation`](Self::from_rotation), then translates points.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_scale_rotation_translation(
+    ///     Vector3::new(2.0, 2.0, 2.0),
+    ///     Vector3::new(0.0, 0.0, core::f64::consts::FRAC_PI_2),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    /// );
+    /// 
+    /// let transformed = affine.transform_point(Vector3::new(1.0, 0.0, 0.0));
+    /// 
+    /// assert!((transformed - Vector3::new(1.0, 2.0, 0.0)).length() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn from_scale_rotation_translation(scale: Vector3<T>, rotation: Vector3<T>, translation: Vector3<T>) -> Self {
+        let rotation = Self::from_rotation(rotation);
+
+        Self::new(
+            rotation.x_axis * scale.x,
+            rotation.y_axis * scale.y,
+            rotation.z_axis * scale.z,
+            translation,
+        )
+    }
+
+    /// Decomposes the transformation into the scale, Euler angles in radians and translation
+    /// which [`from_scale_rotation_translation`](Self::from_scale_rotation_translation) would build it from.
+    /// A reflection is represented by a negative `x` scale.
+    /// 
+    /// If any axis has zero length, its scale is zero and the returned rotation contains `NaN` components.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Affine3, Vector3};
+    /// 
+    /// let affine = Affine3::from_scale_rotation_translation(
+    ///     Vector3::new(2.0, 3.0, 4.0), Vector3::new(0.1, 0.2, 0.3), Vector3::new(1.0, 2.0, 3.0)
+    /// );
+    /// 
+    /// let (scale, rotation, translation) = affine.to_scale_rotation_translation();
+    /// 
+    /// assert!((scale - Vector3::new(2.0, 3.0, 4.0)).length() < 1e-9);
+    /// assert!((rotation - Vector3::new(0.1, 0.2, 0.3)).length() < 1e-9);
+    /// assert_eq!(translation, Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn to_scale_rotation_translation(&self) -> (Vector3<T>, Vector3<T>, Vector3<T>) {
+        let scale = Vector3::new(
+            self.x_axis.length() * self.determinant().signum(),
+            self.y_axis.length(),
+            self.z_axis.length(),
+        );
+
+        let x_axis = self.x_axis / scale.x;
+        let y_axis = self.y_axis / scale.y;
+        let z_axis = self.z_axis / scale.z;
+
+        let sin_y = (-x_axis.z).max(-T::one()).min(T::one());
+        let cos_y = (T::one() - (sin_y * sin_y)).sqrt();
+
+        // NOTE: At a `y` rotation of +-90 degrees the `x` and `z` rotations share an axis,
+        // so the whole remaining rotation is attributed to `x`.
+        let rotation = if cos_y > T::epsilon() {
+            Vector3::new(y_axis.z.atan2(z_axis.z), sin_y.asin(), x_axis.y.atan2(x_axis.x))
+        } else {
+            Vector3::new((sin_y * y_axis.x).atan2(y_axis.y), sin_y.asin(), T::zero())
+        };
+
+        (scale, rotation, self.translation)
+    }
+}
+
+
+impl<T: FloatCore> Default for Affine3<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+
+impl<T: FloatCore> core::ops::Mul for Affine3<T> {
+    type Output = Self;
+
+    /// Composes the two transformations, producing one which applies `other` first and then `self`.
+    fn mul(self, other: Self) -> Self::Output {
+        Self::new(
+            self.transform_vector(other.x_axis),
+            self.transform_vector(other.y_axis),
+            self.transform_vector(other.z_axis),
+            self.transform_point(other.translation),
+        )
+    }
+}
+
+
+impl<T: FloatCore> core::ops::MulAssign for Affine3<T> {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
@@ -0,0 +1,34 @@
+use core::fmt;
+
+
+/// Error returned when a buffer is too short to write a vector to, or read a vector from.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{BufferLengthError, Vector2};
+/// 
+/// let mut buffer = [0; 6];
+/// 
+/// assert_eq!(Vector2::new(1_u16, 2).write_to(&mut buffer), Ok(4));
+/// assert_eq!(Vector2::<u16>::read_from(&buffer), Ok(Vector2::new(1, 2)));
+/// assert_eq!(Vector2::<u32>::read_from(&buffer), Err(BufferLengthError { required: 8, found: 6 }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferLengthError {
+    /// The number of bytes required by the vector.
+    pub required: usize,
+
+    /// The number of bytes available in the buffer.
+    pub found: usize,
+}
+
+
+impl fmt::Display for BufferLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer of {} bytes is too short, {} bytes are required", self.found, self.required)
+    }
+}
+
+
+impl core::error::Error for BufferLengthError {  }
\ No newline at end of file
@@ -0,0 +1,787 @@
+use crate::macros::floating::FloatingPoint;
+use core::fmt;
+use core::num::FpCategory;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use num_traits::float::FloatCore;
+use num_traits::{Num, NumCast, One, ToPrimitive, Zero};
+
+
+/// Fixed-point number stored as a raw integer `I` with `FRAC` fractional bits, for deterministic
+/// math that produces bit-identical results on every platform, such as lockstep simulations.
+/// 
+/// Arithmetic saturates at [`MIN`](Self::MIN) and [`MAX`](Self::MAX), which stand in for negative and positive infinity,
+/// and division by zero saturates instead of panicking. There is no `NaN`, so operations that would produce one return zero.
+/// This includes [`FloatCore::nan`], which returns [`ZERO`](Self::ZERO) while [`FloatCore::is_nan`] is always `false`,
+/// so generic code can't use `NaN` as a sentinel for fixed-point numbers.
+/// `FRAC` must be less than the bit width of `I` minus one, so that [`ONE`](Self::ONE) is representable,
+/// which is checked at compile time.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Q16_16, Vector3};
+/// 
+/// let vec3 = Vector3::new(Q16_16::from_int(3), Q16_16::from_int(4), Q16_16::from_int(0));
+/// 
+/// assert_eq!(vec3.length(), Q16_16::from_int(5));
+/// assert!((vec3.normalized().x.to_f64() - 0.6).abs() < 1e-4);
+/// ```
+/// 
+/// ```compile_fail
+/// use fixed_vectors::Fixed;
+/// 
+/// // There is no room left for an integer bit, so `ONE` can't be represented.
+/// let one = Fixed::<i16, 15>::ONE;
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+
+/// Fixed-point number with 8 integer bits and 8 fractional bits.
+pub type Q8_8 = Fixed<i16, 8>;
+
+/// Fixed-point number with 16 integer bits and 16 fractional bits.
+pub type Q16_16 = Fixed<i32, 16>;
+
+/// Fixed-point number with 32 integer bits and 32 fractional bits.
+pub type Q32_32 = Fixed<i64, 32>;
+
+
+// Fractional bits of the intermediate representation used by the transcendental functions.
+const Q: u32 = 60;
+
+const PI: i128 = 3622009729038561421;
+const HALF_PI: i128 = PI / 2;
+const TWO_PI: i128 = PI * 2;
+const CORDIC_GAIN: i128 = 700114967507363238;
+const DEGREES_PER_RADIAN: i128 = 66057536323845027596;
+const RADIANS_PER_DEGREE: i128 = 20122276272436452;
+
+// `atan(2^-i)` for each CORDIC iteration `i`.
+const ATAN_TABLE: [i128; 61] = [
+    905502432259640355, 534549298976576474, 282441168888798124, 143371547418228444,
+    71963988336308046, 36017075762092179, 18012932708689205, 9007016009513623,
+    4503576721087964, 2251796950380271, 1125899548928887, 562949908682076,
+    281474971118251, 140737487656277, 70368744090283, 35184372077909,
+    17592186043051, 8796093022037, 4398046511083, 2199023255549,
+    1099511627776, 549755813888, 274877906944, 137438953472,
+    68719476736, 34359738368, 17179869184, 8589934592,
+    4294967296, 2147483648, 1073741824, 536870912,
+    268435456, 134217728, 67108864, 33554432,
+    16777216, 8388608, 4194304, 2097152,
+    1048576, 524288, 262144, 131072,
+    65536, 32768, 16384, 8192,
+    4096, 2048, 1024, 512,
+    256, 128, 64, 32,
+    16, 8, 4, 2,
+    1,
+];
+
+// `2^(2^-k)` for each fractional bit `k`, starting at `k = 1`.
+const EXP2_TABLE: [i128; 60] = [
+    1630477228166597777, 1371062456318104878, 1257269815929830109, 1203965700457697123,
+    1178167198179750971, 1165475996665667822, 1159181754367685998, 1156047391917129430,
+    1154483390303177194, 1153702183144312517, 1153311777820267002, 1153116624702523244,
+    1153019060527275927, 1152970281535252809, 1152945892813103188, 1152933698645489088,
+    1152927601610046619, 1152924553104416455, 1152923028854624131, 1152922266730483658,
+    1152921885668602343, 1152921695137708916, 1152921599872274010, 1152921552239559509,
+    1152921528423202997, 1152921516515024925, 1152921510560935935, 1152921507583891452,
+    1152921506095369213, 1152921505351108094, 1152921504978977535, 1152921504792912256,
+    1152921504699879616, 1152921504653363296, 1152921504630105136, 1152921504618476056,
+    1152921504612661516, 1152921504609754246, 1152921504608300611, 1152921504607573793,
+    1152921504607210385, 1152921504607028680, 1152921504606937828, 1152921504606892402,
+    1152921504606869689, 1152921504606858333, 1152921504606852654, 1152921504606849815,
+    1152921504606848396, 1152921504606847686, 1152921504606847331, 1152921504606847153,
+    1152921504606847065, 1152921504606847020, 1152921504606846998, 1152921504606846987,
+    1152921504606846982, 1152921504606846979, 1152921504606846977, 1152921504606846977,
+];
+
+
+// Scales `value` by `2^frac` and rounds it to the nearest integer, away from zero on ties.
+#[inline(always)]
+fn round_scaled(value: f64, frac: u32) -> i128 {
+    let scaled = value * ((1_i128 << frac) as f64);
+
+    if scaled < 0.0 {
+        (scaled - 0.5) as i128
+    } else {
+        (scaled + 0.5) as i128
+    }
+}
+
+
+#[inline(always)]
+const fn rescale(value: i128, from: u32, to: u32) -> i128 {
+    if to >= from {
+        value << (to - from)
+    } else {
+        value >> (from - to)
+    }
+}
+
+
+// Multiplies `value` by a factor in the intermediate representation, keeping 32 fractional
+// bits of the factor so the product can't overflow.
+#[inline(always)]
+const fn scale_by(value: i128, factor: i128) -> i128 {
+    (value * (factor >> (Q - 32))) >> 32
+}
+
+
+fn isqrt(value: u128) -> u128 {
+    if value == 0 {
+        return 0;
+    }
+
+    let mut remainder = value;
+    let mut root = 0;
+    let mut bit = 1 << ((127 - value.leading_zeros()) & !1);
+
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+
+        bit >>= 2;
+    }
+
+    root
+}
+
+
+// Returns the sine and cosine of `angle` using CORDIC in rotation mode.
+fn sin_cos(angle: i128) -> (i128, i128) {
+    let mut angle = angle.rem_euclid(TWO_PI);
+    let mut negate = false;
+
+    if angle > PI {
+        angle -= TWO_PI;
+    }
+
+    // NOTE: CORDIC only converges within roughly a quarter turn, so the remaining
+    // half of the circle is rotated by `PI` and the result negated.
+    if angle > HALF_PI {
+        angle -= PI;
+        negate = true;
+    } else if angle < -HALF_PI {
+        angle += PI;
+        negate = true;
+    }
+
+    let (mut x, mut y, mut z) = (CORDIC_GAIN, 0, angle);
+
+    for (i, step) in ATAN_TABLE.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+
+        if z >= 0 {
+            (x, y, z) = (x - dx, y + dy, z - step);
+        } else {
+            (x, y, z) = (x + dx, y - dy, z + step);
+        }
+    }
+
+    if negate { (-y, -x) } else { (y, x) }
+}
+
+
+// Returns the angle of the point `(x, y)` using CORDIC in vectoring mode.
+fn atan2(y: i128, x: i128) -> i128 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    let bits = 128 - x.unsigned_abs().max(y.unsigned_abs()).leading_zeros();
+    let (mut x, mut y) = (rescale(x, bits, Q), rescale(y, bits, Q));
+    let mut z = 0;
+
+    if x < 0 {
+        (x, y, z) = if y >= 0 { (y, -x, HALF_PI) } else { (-y, x, -HALF_PI) };
+    }
+
+    for (i, step) in ATAN_TABLE.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+
+        if y > 0 {
+            (x, y, z) = (x + dx, y - dy, z + step);
+        } else {
+            (x, y, z) = (x - dx, y + dy, z - step);
+        }
+    }
+
+    z
+}
+
+
+macro_rules! impl_fixed {
+    ( $($int: ty), + ) => {
+        $(
+            impl<const FRAC: u32> Fixed<$int, FRAC> {
+                // NOTE: Evaluated by the constructors, as associated constants are only checked when used.
+                const VALID_FRAC: () = assert!(FRAC < <$int>::BITS - 1, "`FRAC` must leave room for a sign and integer bit");
+
+                /// The value `0`.
+                pub const ZERO: Self = Self(0);
+
+                /// The value `1`.
+                pub const ONE: Self = {
+                    let () = Self::VALID_FRAC;
+                    Self(1 << FRAC)
+                };
+
+                /// The smallest representable value, which also acts as negative infinity.
+                pub const MIN: Self = Self(<$int>::MIN);
+
+                /// The largest representable value, which also acts as positive infinity.
+                pub const MAX: Self = Self(<$int>::MAX);
+
+                /// The smallest positive representable value.
+                pub const EPSILON: Self = Self(1);
+
+                /// Archimedes' constant, `π`, saturating at [`MAX`](Self::MAX) when there are too few integer bits.
+                pub const PI: Self = {
+                    let pi = rescale(PI, Q, FRAC);
+                    Self(if pi > <$int>::MAX as i128 { <$int>::MAX } else { pi as $int })
+                };
+
+                /// Constructs a fixed-point number from its raw integer representation.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// let half = Q16_16::from_bits(0x8000);
+                /// 
+                /// assert_eq!(half.to_f64(), 0.5);
+                /// ```
+                #[inline(always)]
+                pub const fn from_bits(bits: $int) -> Self {
+                    let () = Self::VALID_FRAC;
+                    Self(bits)
+                }
+
+                /// Returns the raw integer representation of the fixed-point number.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// assert_eq!(Q16_16::from_int(3).to_bits(), 3 << 16);
+                /// assert_eq!(Q16_16::ONE.to_bits(), 0x10000);
+                /// ```
+                #[inline(always)]
+                pub const fn to_bits(self) -> $int {
+                    self.0
+                }
+
+                /// Constructs a fixed-point number from an integer, discarding any integer bits that don't fit.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// let three = Q16_16::from_int(3);
+                /// 
+                /// assert_eq!(three.to_f64(), 3.0);
+                /// assert_eq!(three, Q16_16::ONE + Q16_16::ONE + Q16_16::ONE);
+                /// ```
+                #[inline(always)]
+                pub const fn from_int(value: $int) -> Self {
+                    let () = Self::VALID_FRAC;
+                    Self(value << FRAC)
+                }
+
+                /// Constructs the fixed-point number nearest to `value`, saturating when out of range.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// assert_eq!(Q16_16::from_f64(1.5).to_bits(), 0x18000);
+                /// assert_eq!(Q16_16::from_f64(1e12), Q16_16::MAX);
+                /// ```
+                pub fn from_f64(value: f64) -> Self {
+                    let () = Self::VALID_FRAC;
+                    Self::saturate(round_scaled(value, FRAC))
+                }
+
+                /// Converts the fixed-point number into the nearest `f64`.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// assert_eq!(Q16_16::from_f64(-2.25).to_f64(), -2.25);
+                /// assert_eq!(Q16_16::EPSILON.to_f64(), 1.0 / 65536.0);
+                /// ```
+                #[inline]
+                pub fn to_f64(self) -> f64 {
+                    self.0 as f64 / ((1_i128 << FRAC) as f64)
+                }
+
+                /// Returns the square root of the number, or zero if it's negative.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// assert_eq!(Q16_16::from_int(9).sqrt(), Q16_16::from_int(3));
+                /// assert_eq!(Q16_16::from_int(-4).sqrt(), Q16_16::ZERO);
+                /// ```
+                #[inline]
+                pub fn sqrt(self) -> Self {
+                    FloatingPoint::sqrt(self)
+                }
+
+                /// Returns the sine of the number, in radians.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// let angle = Q16_16::from_f64(core::f64::consts::FRAC_PI_6);
+                /// 
+                /// assert!((angle.sin().to_f64() - 0.5).abs() < 1e-4);
+                /// ```
+                #[inline]
+                pub fn sin(self) -> Self {
+                    FloatingPoint::sin(self)
+                }
+
+                /// Returns the cosine of the number, in radians.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// let angle = Q16_16::from_f64(core::f64::consts::FRAC_PI_3);
+                /// 
+                /// assert!((angle.cos().to_f64() - 0.5).abs() < 1e-4);
+                /// ```
+                #[inline]
+                pub fn cos(self) -> Self {
+                    FloatingPoint::cos(self)
+                }
+
+                /// Returns the four quadrant arctangent of `self` and `other`, in radians.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// let angle = Q16_16::from_int(1).atan2(Q16_16::from_int(-1));
+                /// 
+                /// assert!((angle.to_f64() - (3.0 * core::f64::consts::FRAC_PI_4)).abs() < 1e-4);
+                /// ```
+                #[inline]
+                pub fn atan2(self, other: Self) -> Self {
+                    FloatingPoint::atan2(self, other)
+                }
+
+                /// Returns `2` raised to the power of the number.
+                /// 
+                /// # Example
+                /// 
+                /// ```
+                /// use fixed_vectors::Q16_16;
+                /// 
+                /// assert_eq!(Q16_16::from_int(3).exp2(), Q16_16::from_int(8));
+                /// assert!((Q16_16::from_f64(0.5).exp2().to_f64() - core::f64::consts::SQRT_2).abs() < 1e-4);
+                /// ```
+                #[inline]
+                pub fn exp2(self) -> Self {
+                    FloatingPoint::exp2(self)
+                }
+
+                #[inline(always)]
+                fn saturate(value: i128) -> Self {
+                    Self(value.clamp(<$int>::MIN as i128, <$int>::MAX as i128) as $int)
+                }
+
+                #[inline(always)]
+                fn fraction_mask() -> $int {
+                    ((1_i128 << FRAC) - 1) as $int
+                }
+
+                #[inline(always)]
+                fn to_intermediate(self) -> i128 {
+                    rescale(self.0 as i128, FRAC, Q)
+                }
+
+                #[inline(always)]
+                fn from_intermediate(value: i128) -> Self {
+                    Self::saturate(rescale(value, Q, FRAC))
+                }
+            }
+
+            impl<const FRAC: u32> fmt::Debug for Fixed<$int, FRAC> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&Self::to_f64(*self), f)
+                }
+            }
+
+            impl<const FRAC: u32> fmt::Display for Fixed<$int, FRAC> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&Self::to_f64(*self), f)
+                }
+            }
+
+            impl<const FRAC: u32> Add for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    Self(self.0.saturating_add(rhs.0))
+                }
+            }
+
+            impl<const FRAC: u32> Sub for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    Self(self.0.saturating_sub(rhs.0))
+                }
+            }
+
+            impl<const FRAC: u32> Mul for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: Self) -> Self {
+                    Self::saturate((self.0 as i128 * rhs.0 as i128) >> FRAC)
+                }
+            }
+
+            impl<const FRAC: u32> Div for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: Self) -> Self {
+                    match (self.0, rhs.0) {
+                        (0, 0) => Self::ZERO,
+                        (numerator, 0) if numerator < 0 => Self::MIN,
+                        (_, 0) => Self::MAX,
+                        (numerator, denominator) => Self::saturate(((numerator as i128) << FRAC) / denominator as i128),
+                    }
+                }
+            }
+
+            impl<const FRAC: u32> Rem for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn rem(self, rhs: Self) -> Self {
+                    if rhs.0 == 0 {
+                        return Self::ZERO;
+                    }
+
+                    Self(self.0.wrapping_rem(rhs.0))
+                }
+            }
+
+            impl<const FRAC: u32> Neg for Fixed<$int, FRAC> {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    Self(self.0.saturating_neg())
+                }
+            }
+
+            impl_fixed_assign!( $int, AddAssign, add_assign, +, SubAssign, sub_assign, -, MulAssign, mul_assign, *, DivAssign, div_assign, /, RemAssign, rem_assign, % );
+
+            impl<const FRAC: u32> Zero for Fixed<$int, FRAC> {
+                #[inline(always)]
+                fn zero() -> Self {
+                    Self::ZERO
+                }
+
+                #[inline(always)]
+                fn is_zero(&self) -> bool {
+                    self.0 == 0
+                }
+            }
+
+            impl<const FRAC: u32> One for Fixed<$int, FRAC> {
+                #[inline(always)]
+                fn one() -> Self {
+                    Self::ONE
+                }
+            }
+
+            impl<const FRAC: u32> Num for Fixed<$int, FRAC> {
+                type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+                fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                    <f64 as Num>::from_str_radix(str, radix).map(Self::from_f64)
+                }
+            }
+
+            impl<const FRAC: u32> ToPrimitive for Fixed<$int, FRAC> {
+                #[inline]
+                fn to_i64(&self) -> Option<i64> {
+                    Some((self.0 as i128 / (1_i128 << FRAC)) as i64)
+                }
+
+                #[inline]
+                fn to_u64(&self) -> Option<u64> {
+                    self.to_i64()?.try_into().ok()
+                }
+
+                #[inline]
+                fn to_f64(&self) -> Option<f64> {
+                    Some(Self::to_f64(*self))
+                }
+            }
+
+            impl<const FRAC: u32> NumCast for Fixed<$int, FRAC> {
+                fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+                    let float = n.to_f64()?;
+
+                    let scaled = match n.to_i64() {
+                        Some(int) if int as f64 == float => (int as i128).checked_shl(FRAC)?,
+                        _ if float.is_finite() => round_scaled(float, FRAC),
+                        _ => return None,
+                    };
+
+                    if scaled < <$int>::MIN as i128 || scaled > <$int>::MAX as i128 {
+                        return None;
+                    }
+
+                    Some(Self(scaled as $int))
+                }
+            }
+
+            impl<const FRAC: u32> FloatCore for Fixed<$int, FRAC> {
+                #[inline(always)]
+                fn infinity() -> Self {
+                    Self::MAX
+                }
+
+                #[inline(always)]
+                fn neg_infinity() -> Self {
+                    Self::MIN
+                }
+
+                /// Returns [`ZERO`](Self::ZERO), as there is no `NaN` to return. The result is a valid number,
+                /// and [`is_nan`](FloatCore::is_nan) returns `false` for it.
+                #[inline(always)]
+                fn nan() -> Self {
+                    Self::ZERO
+                }
+
+                #[inline(always)]
+                fn neg_zero() -> Self {
+                    Self::ZERO
+                }
+
+                #[inline(always)]
+                fn min_value() -> Self {
+                    Self::MIN
+                }
+
+                #[inline(always)]
+                fn min_positive_value() -> Self {
+                    Self::EPSILON
+                }
+
+                #[inline(always)]
+                fn epsilon() -> Self {
+                    Self::EPSILON
+                }
+
+                #[inline(always)]
+                fn max_value() -> Self {
+                    Self::MAX
+                }
+
+                /// Always returns `false`, as every fixed-point number is a valid number,
+                /// including the result of [`nan`](FloatCore::nan).
+                #[inline(always)]
+                fn is_nan(self) -> bool {
+                    false
+                }
+
+                fn classify(self) -> FpCategory {
+                    if self.0 == 0 {
+                        FpCategory::Zero
+                    } else if self.is_infinite() {
+                        FpCategory::Infinite
+                    } else {
+                        FpCategory::Normal
+                    }
+                }
+
+                #[inline]
+                fn floor(self) -> Self {
+                    Self(self.0 & !Self::fraction_mask())
+                }
+
+                #[inline]
+                fn ceil(self) -> Self {
+                    if self.0 & Self::fraction_mask() == 0 {
+                        return self;
+                    }
+
+                    self.floor() + Self::ONE
+                }
+
+                #[inline]
+                fn round(self) -> Self {
+                    let half = Self(((1_i128 << FRAC) >> 1) as $int);
+
+                    if self.0 < 0 {
+                        -((-self) + half).floor()
+                    } else {
+                        (self + half).floor()
+                    }
+                }
+
+                #[inline]
+                fn trunc(self) -> Self {
+                    Self(self.0 - self.fract().0)
+                }
+
+                #[inline]
+                fn fract(self) -> Self {
+                    Self((self.0 as i128 % (1_i128 << FRAC)) as $int)
+                }
+
+                #[inline]
+                fn abs(self) -> Self {
+                    Self(self.0.saturating_abs())
+                }
+
+                #[inline]
+                fn signum(self) -> Self {
+                    if self.0 < 0 { -Self::ONE } else { Self::ONE }
+                }
+
+                #[inline(always)]
+                fn is_sign_positive(self) -> bool {
+                    self.0 >= 0
+                }
+
+                #[inline(always)]
+                fn is_sign_negative(self) -> bool {
+                    self.0 < 0
+                }
+
+                #[inline]
+                fn to_degrees(self) -> Self {
+                    Self::saturate(scale_by(self.0 as i128, DEGREES_PER_RADIAN))
+                }
+
+                #[inline]
+                fn to_radians(self) -> Self {
+                    Self::saturate(scale_by(self.0 as i128, RADIANS_PER_DEGREE))
+                }
+
+                #[inline]
+                fn integer_decode(self) -> (u64, i16, i8) {
+                    (self.0.unsigned_abs() as u64, -(FRAC as i16), if self.0 < 0 { -1 } else { 1 })
+                }
+            }
+
+            impl<const FRAC: u32> FloatingPoint for Fixed<$int, FRAC> {
+                fn sqrt(self) -> Self {
+                    if self.0 <= 0 {
+                        return Self::ZERO;
+                    }
+
+                    Self::saturate(isqrt((self.0 as u128) << FRAC) as i128)
+                }
+
+                fn sin(self) -> Self {
+                    Self::from_intermediate(sin_cos(self.to_intermediate()).0)
+                }
+
+                fn cos(self) -> Self {
+                    Self::from_intermediate(sin_cos(self.to_intermediate()).1)
+                }
+
+                fn asin(self) -> Self {
+                    self.atan2((Self::ONE - (self * self)).sqrt())
+                }
+
+                fn acos(self) -> Self {
+                    (Self::ONE - (self * self)).sqrt().atan2(self)
+                }
+
+                fn atan2(self, other: Self) -> Self {
+                    Self::from_intermediate(atan2(self.0 as i128, other.0 as i128))
+                }
+
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    Self::saturate(((self.0 as i128 * a.0 as i128) + ((b.0 as i128) << FRAC)) >> FRAC)
+                }
+
+                fn copysign(self, sign: Self) -> Self {
+                    if sign.0 < 0 { -self.abs() } else { self.abs() }
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    if rhs.0 == 0 {
+                        return Self::ZERO;
+                    }
+
+                    Self(self.0.wrapping_rem_euclid(rhs.0))
+                }
+
+                fn exp2(self) -> Self {
+                    let integer = (self.0 >> FRAC) as i128;
+                    let fraction = rescale((self.0 & Self::fraction_mask()) as i128, FRAC, Q);
+                    let mut result = 1_i128 << Q;
+
+                    for (k, factor) in EXP2_TABLE.iter().enumerate() {
+                        if fraction & (1 << (Q - 1 - k as u32)) != 0 {
+                            result = (result * factor) >> Q;
+                        }
+                    }
+
+                    let shift = integer + FRAC as i128 - Q as i128;
+
+                    if shift >= <$int>::BITS as i128 {
+                        Self::MAX
+                    } else if shift >= 0 {
+                        Self::saturate(result << shift)
+                    } else if shift > -(i128::BITS as i128) {
+                        Self::saturate(result >> -shift)
+                    } else {
+                        Self::ZERO
+                    }
+                }
+            }
+        ) +
+    };
+}
+
+
+macro_rules! impl_fixed_assign {
+    ( $int: ty, $($trait: ident, $func: ident, $operator: tt), + ) => {
+        $(
+            impl<const FRAC: u32> $trait for Fixed<$int, FRAC> {
+                #[inline]
+                fn $func(&mut self, rhs: Self) {
+                    *self = *self $operator rhs;
+                }
+            }
+        ) +
+    };
+}
+
+
+impl_fixed!(i16, i32, i64);
\ No newline at end of file
@@ -0,0 +1,5 @@
+mod primitives2;
+mod primitives3;
+
+pub use primitives2::{Circle, Line2, Polygon, Segment2, Triangle2};
+pub use primitives3::{Plane, Ray3, RayHit, Sphere, Triangle3};
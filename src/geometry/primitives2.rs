@@ -0,0 +1,549 @@
+use crate::{Vector2, Vector3};
+use crate::macros::floating::FloatingPoint;
+use num_traits::float::FloatCore;
+
+
+/// Infinite line passing through `point` and extending both ways along `direction`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Line2, Vector2};
+/// 
+/// let a = Line2::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+/// let b = Line2::new(Vector2::new(4.0, 0.0), Vector2::new(0.0, 1.0));
+/// 
+/// assert_eq!(a.intersection(&b), Some(Vector2::new(4.0, 4.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Line2<T> {
+    pub point: Vector2<T>,
+    pub direction: Vector2<T>,
+}
+
+
+/// Line segment between the points `start` and `end`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Segment2, Vector2};
+/// 
+/// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0));
+/// 
+/// assert_eq!(segment.closest_point(Vector2::new(2.0, 3.0)), Vector2::new(2.0, 0.0));
+/// assert_eq!(segment.distance_to(Vector2::new(7.0, 4.0)), 5.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Segment2<T> {
+    pub start: Vector2<T>,
+    pub end: Vector2<T>,
+}
+
+
+/// Circle of the given `radius` around `center`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Circle, Vector2};
+/// 
+/// let circle = Circle::new(Vector2::new(0.0, 0.0), 2.0);
+/// 
+/// assert!(circle.contains(Vector2::new(1.0, 1.0)));
+/// assert!(!circle.contains(Vector2::new(2.0, 2.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Circle<T> {
+    pub center: Vector2<T>,
+    pub radius: T,
+}
+
+
+/// Triangle in two-dimensional space with the vertices `a`, `b` and `c`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Triangle2, Vector2, Vector3};
+/// 
+/// let triangle = Triangle2::new(
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(4.0, 0.0),
+///     Vector2::new(0.0, 4.0),
+/// );
+/// 
+/// assert_eq!(triangle.barycentric(Vector2::new(1.0, 1.0)), Vector3::new(0.5, 0.25, 0.25));
+/// assert!(triangle.contains(Vector2::new(1.0, 1.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Triangle2<T> {
+    pub a: Vector2<T>,
+    pub b: Vector2<T>,
+    pub c: Vector2<T>,
+}
+
+
+/// Closed polygon made of `N` vertices, with an edge between each consecutive pair of vertices
+/// and between the last vertex and the first.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Polygon, Vector2};
+/// 
+/// let polygon = Polygon::new([
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(4.0, 0.0),
+///     Vector2::new(4.0, 4.0),
+///     Vector2::new(2.0, 1.0),
+///     Vector2::new(0.0, 4.0),
+/// ]);
+/// 
+/// assert!(polygon.contains(Vector2::new(1.0, 1.0)));
+/// assert!(!polygon.contains(Vector2::new(2.0, 3.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Polygon<T, const N: usize> {
+    pub vertices: [Vector2<T>; N],
+}
+
+
+impl<T> Line2<T> {
+    /// Constructs a new line passing through `point` along `direction`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Line2, Vector2};
+    /// 
+    /// let line = Line2::new(Vector2::new(0.0, 1.0), Vector2::new(1.0, 0.0));
+    /// 
+    /// assert_eq!(line.direction, Vector2::new(1.0, 0.0));
+    /// ```
+    #[inline(always)]
+    pub const fn new(point: Vector2<T>, direction: Vector2<T>) -> Self {
+        Self { point, direction }
+    }
+}
+
+
+impl<T> Segment2<T> {
+    /// Constructs a new segment between `start` and `end`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Segment2, Vector2};
+    /// 
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(3.0, 4.0));
+    /// 
+    /// assert_eq!(segment.end, Vector2::new(3.0, 4.0));
+    /// ```
+    #[inline(always)]
+    pub const fn new(start: Vector2<T>, end: Vector2<T>) -> Self {
+        Self { start, end }
+    }
+}
+
+
+impl<T> Circle<T> {
+    /// Constructs a new circle of the given `radius` around `center`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Circle, Vector2};
+    /// 
+    /// let circle = Circle::new(Vector2::new(1.0, 1.0), 2.0);
+    /// 
+    /// assert_eq!(circle.radius, 2.0);
+    /// ```
+    #[inline(always)]
+    pub const fn new(center: Vector2<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+
+impl<T> Triangle2<T> {
+    /// Constructs a new triangle from its three vertices.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Triangle2, Vector2};
+    /// 
+    /// let triangle = Triangle2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 4.0));
+    /// 
+    /// assert_eq!(triangle.b, Vector2::new(4.0, 0.0));
+    /// ```
+    #[inline(always)]
+    pub const fn new(a: Vector2<T>, b: Vector2<T>, c: Vector2<T>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+
+impl<T, const N: usize> Polygon<T, N> {
+    /// Constructs a new polygon from its vertices.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Polygon, Vector2};
+    /// 
+    /// let square = Polygon::new([Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(2.0, 2.0), Vector2::new(0.0, 2.0)]);
+    /// 
+    /// assert_eq!(square.vertices.len(), 4);
+    /// ```
+    #[inline(always)]
+    pub const fn new(vertices: [Vector2<T>; N]) -> Self {
+        Self { vertices }
+    }
+}
+
+
+impl<T: FloatCore> Line2<T> {
+    /// Returns the point on the line closest to `point`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Line2, Vector2};
+    /// 
+    /// let line = Line2::new(Vector2::new(0.0, 1.0), Vector2::new(2.0, 0.0));
+    /// 
+    /// assert_eq!(line.closest_point(Vector2::new(-5.0, 3.0)), Vector2::new(-5.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let length_squared = self.direction.length_squared();
+
+        if length_squared == T::zero() {
+            return self.point;
+        }
+
+        let weight = (point - self.point).dot(&self.direction) / length_squared;
+        self.point + (self.direction * weight)
+    }
+
+    /// Returns the point at which the two lines cross, or [`None`] if they are parallel.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Line2, Vector2};
+    /// 
+    /// let line = Line2::new(Vector2::new(0.0, 1.0), Vector2::new(1.0, 0.0));
+    /// 
+    /// assert_eq!(line.intersection(&Line2::new(Vector2::new(3.0, 0.0), Vector2::new(0.0, 1.0))), Some(Vector2::new(3.0, 1.0)));
+    /// assert_eq!(line.intersection(&Line2::new(Vector2::new(0.0, 5.0), Vector2::new(4.0, 0.0))), None);
+    /// ```
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Vector2<T>> {
+        let denominator = self.direction.perp_dot(&other.direction);
+
+        if denominator == T::zero() {
+            return None;
+        }
+
+        let weight = (other.point - self.point).perp_dot(&other.direction) / denominator;
+
+        if !weight.is_finite() {
+            return None;
+        }
+
+        Some(self.point + (self.direction * weight))
+    }
+}
+
+
+impl<T: FloatCore> Segment2<T> {
+    /// Returns the point on the segment closest to `point`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Segment2, Vector2};
+    /// 
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0));
+    /// 
+    /// assert_eq!(segment.closest_point(Vector2::new(2.0, 3.0)), Vector2::new(2.0, 0.0));
+    /// assert_eq!(segment.closest_point(Vector2::new(6.0, 3.0)), Vector2::new(4.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        let delta = self.end - self.start;
+        let length_squared = delta.length_squared();
+
+        if length_squared == T::zero() {
+            return self.start;
+        }
+
+        let weight = ((point - self.start).dot(&delta) / length_squared)
+            .max(T::zero())
+            .min(T::one());
+
+        self.start + (delta * weight)
+    }
+
+    /// Returns the point at which the two segments cross, or [`None`] if they do not cross or are parallel.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Segment2, Vector2};
+    /// 
+    /// let a = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 4.0));
+    /// let b = Segment2::new(Vector2::new(0.0, 4.0), Vector2::new(4.0, 0.0));
+    /// 
+    /// assert_eq!(a.intersection(&b), Some(Vector2::new(2.0, 2.0)));
+    /// assert_eq!(a.intersection(&Segment2::new(Vector2::new(5.0, 0.0), Vector2::new(5.0, 4.0))), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Vector2<T>> {
+        let delta = self.end - self.start;
+        let other_delta = other.end - other.start;
+        let denominator = delta.perp_dot(&other_delta);
+
+        if denominator == T::zero() {
+            return None;
+        }
+
+        let offset = other.start - self.start;
+        let weight = offset.perp_dot(&other_delta) / denominator;
+        let other_weight = offset.perp_dot(&delta) / denominator;
+
+        let range = T::zero()..=T::one();
+
+        if !range.contains(&weight) || !range.contains(&other_weight) {
+            return None;
+        }
+
+        Some(self.start + (delta * weight))
+    }
+
+    /// Returns `true` if the two segments cross.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Segment2, Vector2};
+    /// 
+    /// let a = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 4.0));
+    /// 
+    /// assert!(a.intersects(&Segment2::new(Vector2::new(0.0, 4.0), Vector2::new(4.0, 0.0))));
+    /// assert!(!a.intersects(&Segment2::new(Vector2::new(5.0, 0.0), Vector2::new(5.0, 4.0))));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Segment2<T> {
+    /// Returns the length of the segment.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Segment2, Vector2};
+    /// 
+    /// let segment = Segment2::new(Vector2::new(1.0, 1.0), Vector2::new(4.0, 5.0));
+    /// 
+    /// assert_eq!(segment.length(), 5.0);
+    /// ```
+    #[inline]
+    pub fn length(&self) -> T {
+        (self.end - self.start).length()
+    }
+
+    /// Returns the shortest distance from the segment to `point`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Segment2, Vector2};
+    /// 
+    /// let segment = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0));
+    /// 
+    /// assert_eq!(segment.distance_to(Vector2::new(2.0, 3.0)), 3.0);
+    /// assert_eq!(segment.distance_to(Vector2::new(7.0, 4.0)), 5.0);
+    /// ```
+    #[inline]
+    pub fn distance_to(&self, point: Vector2<T>) -> T {
+        (point - self.closest_point(point)).length()
+    }
+}
+
+
+impl<T: FloatCore> Circle<T> {
+    /// Returns `true` if `point` lies within or on the edge of the circle.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Circle, Vector2};
+    /// 
+    /// let circle = Circle::new(Vector2::new(1.0, 1.0), 1.0);
+    /// 
+    /// assert!(circle.contains(Vector2::new(2.0, 1.0)));
+    /// assert!(!circle.contains(Vector2::new(2.0, 2.0)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: Vector2<T>) -> bool {
+        (point - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    /// Returns `true` if the two circles overlap or touch.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Circle, Vector2};
+    /// 
+    /// let circle = Circle::new(Vector2::new(0.0, 0.0), 1.0);
+    /// 
+    /// assert!(circle.intersects(&Circle::new(Vector2::new(3.0, 0.0), 2.0)));
+    /// assert!(!circle.intersects(&Circle::new(Vector2::new(3.0, 0.0), 1.0)));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let radii = self.radius + other.radius;
+        (other.center - self.center).length_squared() <= radii * radii
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Circle<T> {
+    /// Returns the point within the circle closest to `point`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Circle, Vector2};
+    /// 
+    /// let circle = Circle::new(Vector2::new(1.0, 1.0), 2.0);
+    /// 
+    /// assert_eq!(circle.closest_point(Vector2::new(1.0, 5.0)), Vector2::new(1.0, 3.0));
+    /// assert_eq!(circle.closest_point(Vector2::new(2.0, 1.0)), Vector2::new(2.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        self.center + (point - self.center).clamp_length_max(self.radius)
+    }
+}
+
+
+impl<T: FloatCore> Triangle2<T> {
+    /// Returns the signed area of the triangle, positive when its vertices are wound counter-clockwise.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Triangle2, Vector2};
+    /// 
+    /// let triangle = Triangle2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 4.0));
+    /// 
+    /// assert_eq!(triangle.signed_area(), 8.0);
+    /// assert_eq!(Triangle2::new(triangle.a, triangle.c, triangle.b).signed_area(), -8.0);
+    /// ```
+    #[inline]
+    pub fn signed_area(&self) -> T {
+        (self.b - self.a).perp_dot(&(self.c - self.a)) / (T::one() + T::one())
+    }
+
+    /// Returns the barycentric coordinates of `point`, the weights of `a`, `b` and `c` which sum to `point`.
+    /// Every coordinate is `NaN` for a degenerate triangle.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Triangle2, Vector2, Vector3};
+    /// 
+    /// let triangle = Triangle2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 4.0));
+    /// 
+    /// assert_eq!(triangle.barycentric(Vector2::new(4.0, 0.0)), Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(triangle.barycentric(Vector2::new(1.0, 1.0)), Vector3::new(0.5, 0.25, 0.25));
+    /// ```
+    #[inline]
+    pub fn barycentric(&self, point: Vector2<T>) -> Vector3<T> {
+        let area = (self.b - self.a).perp_dot(&(self.c - self.a));
+        let v = (point - self.a).perp_dot(&(self.c - self.a)) / area;
+        let w = (self.b - self.a).perp_dot(&(point - self.a)) / area;
+
+        Vector3::new(T::one() - v - w, v, w)
+    }
+
+    /// Returns `true` if `point` lies within or on an edge of the triangle.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Triangle2, Vector2};
+    /// 
+    /// let triangle = Triangle2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 4.0));
+    /// 
+    /// assert!(triangle.contains(Vector2::new(1.0, 1.0)));
+    /// assert!(!triangle.contains(Vector2::new(3.0, 3.0)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: Vector2<T>) -> bool {
+        let coordinates = self.barycentric(point);
+        coordinates.x >= T::zero() && coordinates.y >= T::zero() && coordinates.z >= T::zero()
+    }
+}
+
+
+impl<T: FloatCore, const N: usize> Polygon<T, N> {
+    /// Returns `true` if `point` lies within the polygon, using the even-odd rule
+    /// so self-intersecting polygons are supported.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Polygon, Vector2};
+    /// 
+    /// let square = Polygon::new([Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(2.0, 2.0), Vector2::new(0.0, 2.0)]);
+    /// 
+    /// assert!(square.contains(Vector2::new(1.0, 1.0)));
+    /// assert!(!square.contains(Vector2::new(3.0, 1.0)));
+    /// ```
+    pub fn contains(&self, point: Vector2<T>) -> bool {
+        let mut inside = false;
+
+        for (index, current) in self.vertices.iter().enumerate() {
+            let previous = self.vertices[(index + N - 1) % N];
+
+            if (current.y > point.y) != (previous.y > point.y) {
+                let crossing = current.x + ((point.y - current.y) * (previous.x - current.x) / (previous.y - current.y));
+
+                if point.x < crossing {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+
+    /// Returns the signed area of the polygon, positive when its vertices are wound counter-clockwise.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Polygon, Vector2};
+    /// 
+    /// let square = Polygon::new([Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(2.0, 2.0), Vector2::new(0.0, 2.0)]);
+    /// 
+    /// assert_eq!(square.signed_area(), 4.0);
+    /// ```
+    pub fn signed_area(&self) -> T {
+        let twice_area = self.vertices.iter()
+            .enumerate()
+            .fold(T::zero(), |sum, (index, current)| sum + current.perp_dot(&self.vertices[(index + 1) % N]));
+
+        twice_area / (T::one() + T::one())
+    }
+}
@@ -0,0 +1,552 @@
+use crate::{Aabb3, Vector3};
+use crate::macros::floating::FloatingPoint;
+use num_traits::float::FloatCore;
+
+
+/// Half-line starting at `origin` and extending infinitely along `direction`.
+/// 
+/// Distances along the ray are measured in multiples of `direction`,
+/// so they are only true distances when `direction` is normalized.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Ray3, Sphere, Vector3};
+/// 
+/// let ray = Ray3::new(Vector3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+/// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0);
+/// let hit = ray.intersect_sphere(&sphere).unwrap();
+/// 
+/// assert_eq!(hit.distance, 4.0);
+/// assert_eq!(hit.point, Vector3::new(0.0, 0.0, -1.0));
+/// assert_eq!(hit.normal, Vector3::new(0.0, 0.0, -1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ray3<T> {
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>,
+}
+
+
+/// Result of a successful ray intersection test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RayHit<T> {
+    /// Distance along the ray at which the hit occurred.
+    pub distance: T,
+    /// Point at which the hit occurred.
+    pub point: Vector3<T>,
+    /// Normalized surface normal at the hit, facing against the ray.
+    pub normal: Vector3<T>,
+}
+
+
+/// Infinite plane containing every point `p` where `normal.dot(&p) == distance`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Plane, Vector3};
+/// 
+/// let plane = Plane::from_point_normal(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+/// 
+/// assert_eq!(plane.signed_distance(Vector3::new(5.0, 5.0, 5.0)), 3.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Plane<T> {
+    pub normal: Vector3<T>,
+    pub distance: T,
+}
+
+
+/// Sphere of the given `radius` around `center`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Sphere, Vector3};
+/// 
+/// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 2.0);
+/// 
+/// assert!(sphere.contains(Vector3::new(0.0, 2.0, 0.0)));
+/// assert!(!sphere.contains(Vector3::new(2.0, 2.0, 0.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Sphere<T> {
+    pub center: Vector3<T>,
+    pub radius: T,
+}
+
+
+/// Triangle in three-dimensional space, wound counter-clockwise from `a` to `b` to `c`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Triangle3, Vector3};
+/// 
+/// let triangle = Triangle3::new(
+///     Vector3::new(0.0, 0.0, 0.0),
+///     Vector3::new(1.0, 0.0, 0.0),
+///     Vector3::new(0.0, 1.0, 0.0),
+/// );
+/// 
+/// assert_eq!(triangle.normal(), Vector3::new(0.0, 0.0, 1.0));
+/// assert_eq!(triangle.area(), 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Triangle3<T> {
+    pub a: Vector3<T>,
+    pub b: Vector3<T>,
+    pub c: Vector3<T>,
+}
+
+
+impl<T> Ray3<T> {
+    /// Constructs a new ray starting at `origin` and extending along `direction`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Ray3, Vector3};
+    /// 
+    /// let ray = Ray3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 0.0, 1.0));
+    /// 
+    /// assert_eq!(ray.origin, Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[inline(always)]
+    pub const fn new(origin: Vector3<T>, direction: Vector3<T>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+
+impl<T> Plane<T> {
+    /// Constructs a new plane from its `normal` and its signed `distance` from the origin along `normal`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Plane, Vector3};
+    /// 
+    /// let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+    /// 
+    /// assert_eq!(plane.signed_distance(Vector3::new(0.0, 5.0, 0.0)), 3.0);
+    /// ```
+    #[inline(always)]
+    pub const fn new(normal: Vector3<T>, distance: T) -> Self {
+        Self { normal, distance }
+    }
+}
+
+
+impl<T> Sphere<T> {
+    /// Constructs a new sphere of the given `radius` around `center`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Sphere, Vector3};
+    /// 
+    /// let sphere = Sphere::new(Vector3::new(1.0, 2.0, 3.0), 4.0);
+    /// 
+    /// assert_eq!(sphere.radius, 4.0);
+    /// ```
+    #[inline(always)]
+    pub const fn new(center: Vector3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+
+impl<T> Triangle3<T> {
+    /// Constructs a new triangle from its three vertices.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Triangle3, Vector3};
+    /// 
+    /// let triangle = Triangle3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// 
+    /// assert_eq!(triangle.c, Vector3::new(0.0, 1.0, 0.0));
+    /// ```
+    #[inline(always)]
+    pub const fn new(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Ray3<T> {
+    /// Returns the point at the given `distance` along the ray.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Ray3, Vector3};
+    /// 
+    /// let ray = Ray3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+    /// 
+    /// assert_eq!(ray.at(1.5), Vector3::new(1.0, 3.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn at(&self, distance: T) -> Vector3<T> {
+        self.origin + (self.direction * distance)
+    }
+
+    /// Returns the nearest hit between the ray and `plane`, or [`None`] if the ray is parallel to
+    /// or points away from the plane.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Plane, Ray3, Vector3};
+    /// 
+    /// let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 0.0);
+    /// let ray = Ray3::new(Vector3::new(2.0, 3.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+    /// let hit = ray.intersect_plane(&plane).unwrap();
+    /// 
+    /// assert_eq!(hit.distance, 3.0);
+    /// assert_eq!(hit.point, Vector3::new(2.0, 0.0, 0.0));
+    /// assert_eq!(hit.normal, Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(Ray3::new(ray.origin, Vector3::new(1.0, 0.0, 0.0)).intersect_plane(&plane), None);
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<RayHit<T>> {
+        let denominator = plane.normal.dot(&self.direction);
+
+        if denominator == T::zero() {
+            return None;
+        }
+
+        let distance = (plane.distance - plane.normal.dot(&self.origin)) / denominator;
+
+        if distance < T::zero() || !distance.is_finite() {
+            return None;
+        }
+
+        let normal = plane.normal.normalized();
+
+        Some(RayHit {
+            distance,
+            point: self.at(distance),
+            normal: if denominator > T::zero() { -normal } else { normal },
+        })
+    }
+
+    /// Returns the nearest hit between the ray and `sphere`, or [`None`] if the ray misses it.
+    /// Rays starting inside the sphere hit its far side.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Ray3, Sphere, Vector3};
+    /// 
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0);
+    /// let inside = Ray3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// let hit = inside.intersect_sphere(&sphere).unwrap();
+    /// 
+    /// assert_eq!(hit.point, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(hit.normal, Vector3::new(-1.0, 0.0, 0.0));
+    /// assert_eq!(Ray3::new(Vector3::new(0.0, 2.0, 0.0), inside.direction).intersect_sphere(&sphere), None);
+    /// ```
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<RayHit<T>> {
+        let offset = self.origin - sphere.center;
+
+        let a = self.direction.length_squared();
+        let half_b = offset.dot(&self.direction);
+        let c = offset.length_squared() - (sphere.radius * sphere.radius);
+
+        let discriminant = (half_b * half_b) - (a * c);
+
+        if discriminant < T::zero() || a == T::zero() {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let mut distance = (-half_b - root) / a;
+
+        if distance < T::zero() {
+            distance = (-half_b + root) / a;
+        }
+
+        if distance < T::zero() {
+            return None;
+        }
+
+        let point = self.at(distance);
+        let normal = (point - sphere.center).normalized();
+
+        Some(RayHit {
+            distance,
+            point,
+            normal: if normal.dot(&self.direction) > T::zero() { -normal } else { normal },
+        })
+    }
+
+    /// Returns the hit between the ray and `triangle` using the Möller–Trumbore algorithm,
+    /// or [`None`] if the ray misses it. Both sides of the triangle can be hit.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Ray3, Triangle3, Vector3};
+    /// 
+    /// let triangle = Triangle3::new(Vector3::new(-1.0, -1.0, 0.0), Vector3::new(1.0, -1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let ray = Ray3::new(Vector3::new(0.0, 0.0, 2.0), Vector3::new(0.0, 0.0, -1.0));
+    /// let hit = ray.intersect_triangle(&triangle).unwrap();
+    /// 
+    /// assert_eq!(hit.point, Vector3::new(0.0, 0.0, 0.0));
+    /// assert_eq!(hit.normal, Vector3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(Ray3::new(Vector3::new(2.0, 0.0, 2.0), ray.direction).intersect_triangle(&triangle), None);
+    /// ```
+    pub fn intersect_triangle(&self, triangle: &Triangle3<T>) -> Option<RayHit<T>> {
+        let edge_1 = triangle.b - triangle.a;
+        let edge_2 = triangle.c - triangle.a;
+
+        let p = self.direction.cross(&edge_2);
+        let determinant = edge_1.dot(&p);
+
+        let inverse_determinant = T::one() / determinant;
+
+        // NOTE: Only an exactly parallel ray is rejected up front, since any fixed tolerance would also reject
+        // small triangles whose determinant scales with the square of their edges.
+        if !inverse_determinant.is_finite() {
+            return None;
+        }
+
+        let s = self.origin - triangle.a;
+        let u = s.dot(&p) * inverse_determinant;
+
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+
+        let q = s.cross(&edge_1);
+        let v = self.direction.dot(&q) * inverse_determinant;
+
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+
+        let distance = edge_2.dot(&q) * inverse_determinant;
+
+        if distance < T::zero() {
+            return None;
+        }
+
+        let normal = edge_1.cross(&edge_2).normalized();
+
+        Some(RayHit {
+            distance,
+            point: self.at(distance),
+            normal: if normal.dot(&self.direction) > T::zero() { -normal } else { normal },
+        })
+    }
+
+    /// Returns the nearest hit between the ray and `aabb` using the slab method, or [`None`] if the ray misses it.
+    /// Rays starting inside the bounding box hit its far side.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Aabb3, Ray3, Vector3};
+    /// 
+    /// let aabb = Aabb3::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let ray = Ray3::new(Vector3::new(-3.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// let hit = ray.intersect_aabb(&aabb).unwrap();
+    /// 
+    /// assert_eq!(hit.distance, 2.0);
+    /// assert_eq!(hit.normal, Vector3::new(-1.0, 0.0, 0.0));
+    /// assert_eq!(Ray3::new(ray.origin, -ray.direction).intersect_aabb(&aabb), None);
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb3<T>) -> Option<RayHit<T>> {
+        let origin = self.origin.to_array();
+        let direction = self.direction.to_array();
+        let (min, max) = (aabb.min.to_array(), aabb.max.to_array());
+
+        let (mut near, mut far) = (T::neg_infinity(), T::infinity());
+        let (mut near_axis, mut far_axis) = (0, 0);
+
+        for axis in 0..3 {
+            let inverse_direction = T::one() / direction[axis];
+            let mut entry = (min[axis] - origin[axis]) * inverse_direction;
+            let mut exit = (max[axis] - origin[axis]) * inverse_direction;
+
+            if inverse_direction < T::zero() {
+                core::mem::swap(&mut entry, &mut exit);
+            }
+
+            if entry > near {
+                near = entry;
+                near_axis = axis;
+            }
+
+            if exit < far {
+                far = exit;
+                far_axis = axis;
+            }
+
+            if far < near {
+                return None;
+            }
+        }
+
+        let (distance, axis) = match (near >= T::zero(), far >= T::zero()) {
+            (true, _) => (near, near_axis),
+            (false, true) => (far, far_axis),
+            (false, false) => return None,
+        };
+
+        let mut normal = [T::zero(); 3];
+        normal[axis] = if direction[axis] > T::zero() { -T::one() } else { T::one() };
+
+        Some(RayHit {
+            distance,
+            point: self.at(distance),
+            normal: Vector3::from(normal),
+        })
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Plane<T> {
+    /// Constructs the plane passing through `point` and facing along `normal`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Plane, Vector3};
+    /// 
+    /// let plane = Plane::from_point_normal(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 3.0, 0.0));
+    /// 
+    /// assert_eq!(plane, Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0));
+    /// ```
+    #[inline]
+    pub fn from_point_normal(point: Vector3<T>, normal: Vector3<T>) -> Self {
+        let normal = normal.normalized();
+        Self::new(normal, normal.dot(&point))
+    }
+
+    /// Constructs the plane passing through the three given points, facing along the normal
+    /// of the counter-clockwise triangle `a`, `b`, `c`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Plane, Vector3};
+    /// 
+    /// let plane = Plane::from_points(Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 1.0));
+    /// 
+    /// assert_eq!(plane, Plane::new(Vector3::new(0.0, 0.0, 1.0), 1.0));
+    /// ```
+    #[inline]
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self::from_point_normal(a, (b - a).cross(&(c - a)))
+    }
+
+    /// Returns the signed distance from the plane to `point`, positive on the side `normal` faces.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Plane, Vector3};
+    /// 
+    /// let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+    /// 
+    /// assert_eq!(plane.signed_distance(Vector3::new(0.0, 5.0, 0.0)), 3.0);
+    /// assert_eq!(plane.signed_distance(Vector3::new(0.0, -1.0, 0.0)), -3.0);
+    /// ```
+    #[inline]
+    pub fn signed_distance(&self, point: Vector3<T>) -> T {
+        self.normal.dot(&point) - self.distance
+    }
+
+    /// Returns the point on the plane closest to `point`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Plane, Vector3};
+    /// 
+    /// let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+    /// 
+    /// assert_eq!(plane.project_point(Vector3::new(3.0, 5.0, 4.0)), Vector3::new(3.0, 2.0, 4.0));
+    /// ```
+    #[inline]
+    pub fn project_point(&self, point: Vector3<T>) -> Vector3<T> {
+        point - (self.normal * self.signed_distance(point))
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Sphere<T> {
+    /// Returns `true` if `point` lies within or on the surface of the sphere.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Sphere, Vector3};
+    /// 
+    /// let sphere = Sphere::new(Vector3::new(1.0, 0.0, 0.0), 1.0);
+    /// 
+    /// assert!(sphere.contains(Vector3::new(2.0, 0.0, 0.0)));
+    /// assert!(!sphere.contains(Vector3::new(-1.0, 0.0, 0.0)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: Vector3<T>) -> bool {
+        (point - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    /// Returns `true` if the two spheres overlap or touch.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Sphere, Vector3};
+    /// 
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0);
+    /// 
+    /// assert!(sphere.intersects(&Sphere::new(Vector3::new(3.0, 0.0, 0.0), 2.0)));
+    /// assert!(!sphere.intersects(&Sphere::new(Vector3::new(3.0, 0.0, 0.0), 1.0)));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let radii = self.radius + other.radius;
+        (other.center - self.center).length_squared() <= radii * radii
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Triangle3<T> {
+    /// Returns the normalized normal of the triangle, following its counter-clockwise winding.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Triangle3, Vector3};
+    /// 
+    /// let triangle = Triangle3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// 
+    /// assert_eq!(triangle.normal(), Vector3::new(0.0, 0.0, -1.0));
+    /// ```
+    #[inline]
+    pub fn normal(&self) -> Vector3<T> {
+        (self.b - self.a).cross(&(self.c - self.a)).normalized()
+    }
+
+    /// Returns the area of the triangle.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Triangle3, Vector3};
+    /// 
+    /// let triangle = Triangle3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0), Vector3::new(0.0, 3.0, 0.0));
+    /// 
+    /// assert_eq!(triangle.area(), 6.0);
+    /// ```
+    #[inline]
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(&(self.c - self.a)).length() / (T::one() + T::one())
+    }
+}
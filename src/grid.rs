@@ -0,0 +1,269 @@
+use crate::{Vector2, Vector3};
+use core::iter::FusedIterator;
+use num_traits::PrimInt;
+
+
+/// Iterator over every integer vector between `start` inclusively and `end` exclusively along each axis,
+/// in row-major order, meaning `x` changes fastest.
+/// 
+/// The iterator is empty if any field of `start` isn't less than the same field of `end`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Vector2, VectorRange};
+/// 
+/// let mut range = VectorRange::new(Vector2::new(0, 0), Vector2::new(2, 2));
+/// 
+/// assert_eq!(range.next(), Some(Vector2::new(0, 0)));
+/// assert_eq!(range.next(), Some(Vector2::new(1, 0)));
+/// assert_eq!(range.next(), Some(Vector2::new(0, 1)));
+/// assert_eq!(range.next(), Some(Vector2::new(1, 1)));
+/// assert_eq!(range.next(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VectorRange<V> {
+    start: V,
+    end: V,
+    next: Option<V>,
+}
+
+
+impl<V: Copy> VectorRange<V> {
+    /// Constructs an iterator over every vector from `start` up to, but excluding, `end`.
+    #[inline]
+    pub const fn new(start: V, end: V) -> Self {
+        Self { start, end, next: Some(start) }
+    }
+
+    /// Returns the lower bound of the range, which is included.
+    #[inline(always)]
+    pub const fn start(&self) -> V {
+        self.start
+    }
+
+    /// Returns the upper bound of the range, which is excluded.
+    #[inline(always)]
+    pub const fn end(&self) -> V {
+        self.end
+    }
+}
+
+
+// Offsets every axis of `axes` by the matching `-1`, `0` or `1` within `offset`,
+// or returns `None` if any of them would overflow.
+#[inline]
+fn offset_axes<T: PrimInt, const N: usize>(mut axes: [T; N], offset: [i8; N]) -> Option<[T; N]> {
+    for (axis, delta) in axes.iter_mut().zip(offset) {
+        *axis = match delta {
+            -1 => axis.checked_sub(&T::one())?,
+            1 => axis.checked_add(&T::one())?,
+            _ => *axis,
+        };
+    }
+
+    Some(axes)
+}
+
+
+// Returns the offsets of the `2 * N` neighbors sharing a face with a cell, ordered by axis.
+#[inline]
+fn von_neumann_offsets<const N: usize>() -> impl Iterator<Item = [i8; N]> {
+    (0..(2 * N)).map(|index| core::array::from_fn(|axis| {
+        if axis != index / 2 {
+            0
+        } else if index % 2 == 0 {
+            -1
+        } else {
+            1
+        }
+    }))
+}
+
+
+// Returns the offsets of the `3^N - 1` neighbors sharing a face, edge or corner with a cell, in row-major order.
+#[inline]
+fn moore_offsets<const N: usize>() -> impl Iterator<Item = [i8; N]> {
+    let count = 3_usize.pow(N as u32);
+
+    (0..count).filter(move |index| *index != count / 2).map(|index| core::array::from_fn(|axis| {
+        (index / 3_usize.pow(axis as u32) % 3) as i8 - 1
+    }))
+}
+
+
+macro_rules! impl_grid {
+    ( $struct: ident { $($field: ident), + }, $size: expr ) => {
+        impl<T: PrimInt> $struct<T> {
+            /// Returns the neighbors sharing a face with the vector, ordered by axis with the lower neighbor first.
+            /// Neighbors whose fields would overflow are skipped.
+            #[inline]
+            pub fn von_neumann_neighbors(self) -> impl Iterator<Item = Self> {
+                von_neumann_offsets::<$size>().filter_map(move |offset| {
+                    offset_axes([ $( self.$field ), + ], offset).map(Self::from)
+                })
+            }
+
+            /// Returns the neighbors sharing a face, edge or corner with the vector, in row-major order.
+            /// Neighbors whose fields would overflow are skipped.
+            #[inline]
+            pub fn moore_neighbors(self) -> impl Iterator<Item = Self> {
+                moore_offsets::<$size>().filter_map(move |offset| {
+                    offset_axes([ $( self.$field ), + ], offset).map(Self::from)
+                })
+            }
+        }
+
+        impl<T: PrimInt> Iterator for VectorRange<$struct<T>> {
+            type Item = $struct<T>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let current = self.next.take()?;
+
+                if $( current.$field >= self.end.$field ) || + {
+                    return None;
+                }
+
+                // NOTE: Steps the fields like the digits of a counter, carrying into the next field on overflow.
+                let mut next = current;
+
+                $(
+                    next.$field = next.$field + T::one();
+
+                    if next.$field < self.end.$field {
+                        self.next = Some(next);
+                        return Some(current);
+                    }
+
+                    next.$field = self.start.$field;
+                ) +
+
+                Some(current)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let Some(current) = self.next else {
+                    return (0, Some(0));
+                };
+
+                if $( current.$field >= self.end.$field ) || + {
+                    return (0, Some(0));
+                }
+
+                // NOTE: Counts the vectors that remain ahead of `current` within each field, starting with the slowest.
+                let mut remaining = Some(0_usize);
+
+                for (current, start, end) in [ $( (current.$field, self.start.$field, self.end.$field) ), + ].into_iter().rev() {
+                    let extent = end.checked_sub(&start).and_then(|extent| extent.to_usize());
+                    let ahead = end.checked_sub(&current).and_then(|ahead| ahead.to_usize()).map(|ahead| ahead - 1);
+
+                    remaining = remaining
+                        .zip(extent)
+                        .and_then(|(remaining, extent)| remaining.checked_mul(extent))
+                        .zip(ahead)
+                        .and_then(|(remaining, ahead)| remaining.checked_add(ahead));
+                }
+
+                match remaining.and_then(|remaining| remaining.checked_add(1)) {
+                    Some(remaining) => (remaining, Some(remaining)),
+                    None => (0, None),
+                }
+            }
+        }
+
+        impl<T: PrimInt> FusedIterator for VectorRange<$struct<T>> {  }
+    };
+}
+
+
+impl_grid!(Vector2 { x, y }, 2);
+impl_grid!(Vector3 { x, y, z }, 3);
+
+
+impl<T: PrimInt> Vector2<T> {
+    /// Returns the index of the vector within a row-major grid `width` cells wide,
+    /// or [`None`] if either field is negative, `x` isn't less than `width`, or the index overflows.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::Vector2;
+    /// 
+    /// assert_eq!(Vector2::new(2, 1).to_index(4), Some(6));
+    /// assert_eq!(Vector2::new(4, 1).to_index(4), None);
+    /// assert_eq!(Vector2::from_index(6, 4), Some(Vector2::new(2, 1)));
+    /// ```
+    #[inline]
+    pub fn to_index(&self, width: usize) -> Option<usize> {
+        let (x, y) = (self.x.to_usize()?, self.y.to_usize()?);
+
+        if x >= width {
+            return None;
+        }
+
+        y.checked_mul(width)?.checked_add(x)
+    }
+
+    /// Constructs a vector from its index within a row-major grid `width` cells wide,
+    /// or returns [`None`] if `width` is zero or a field doesn't fit within `T`.
+    #[inline]
+    pub fn from_index(index: usize, width: usize) -> Option<Self> {
+        if width == 0 {
+            return None;
+        }
+
+        Some(Self {
+            x: T::from(index % width)?,
+            y: T::from(index / width)?,
+        })
+    }
+}
+
+
+impl<T: PrimInt> Vector3<T> {
+    /// Returns the index of the vector within a row-major grid `width` cells wide and `height` cells tall,
+    /// or [`None`] if any field is negative, `x` or `y` are out of bounds, or the index overflows.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::Vector3;
+    /// 
+    /// assert_eq!(Vector3::new(2, 1, 3).to_index(4, 5), Some(66));
+    /// assert_eq!(Vector3::new(2, 5, 3).to_index(4, 5), None);
+    /// ```
+    #[inline]
+    pub fn to_index(&self, width: usize, height: usize) -> Option<usize> {
+        let (x, y, z) = (self.x.to_usize()?, self.y.to_usize()?, self.z.to_usize()?);
+
+        if x >= width || y >= height {
+            return None;
+        }
+
+        z.checked_mul(height)?.checked_add(y)?.checked_mul(width)?.checked_add(x)
+    }
+
+    /// Constructs a vector from its index within a row-major grid `width` cells wide and `height` cells tall,
+    /// or returns [`None`] if `width` or `height` are zero or a field doesn't fit within `T`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::Vector3;
+    /// 
+    /// assert_eq!(Vector3::from_index(66, 4, 5), Some(Vector3::new(2, 1, 3)));
+    /// assert_eq!(Vector3::<u8>::from_index(66, 4, 0), None);
+    /// ```
+    #[inline]
+    pub fn from_index(index: usize, width: usize, height: usize) -> Option<Self> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        Some(Self {
+            x: T::from(index % width)?,
+            y: T::from(index / width % height)?,
+            z: T::from(index / width / height)?,
+        })
+    }
+}
\ No newline at end of file
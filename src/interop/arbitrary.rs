@@ -0,0 +1,27 @@
+use crate::{Vector2, Vector3, Vector4, Vector3A, Vector4A};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+
+macro_rules! impl_arbitrary {
+    ( $struct: ident { $($field: ident), + }, $size: expr ) => {
+        impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for $struct<T> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                Ok(Self {
+                    $( $field: u.arbitrary()? ), +
+                })
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                arbitrary::size_hint::and_all(&[T::size_hint(depth); $size])
+            }
+        }
+    };
+}
+
+
+impl_arbitrary!(Vector2 { x, y }, 2);
+impl_arbitrary!(Vector3 { x, y, z }, 3);
+impl_arbitrary!(Vector4 { x, y, z, w }, 4);
+impl_arbitrary!(Vector3A { x, y, z }, 3);
+impl_arbitrary!(Vector4A { x, y, z, w }, 4);
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::{Vector2, Vector3, Vector4};
+
+
+macro_rules! impl_cgmath {
+    ( $struct: ident { $($field: ident), + } ) => {
+        impl<T> From<cgmath::$struct<T>> for $struct<T> {
+            #[inline]
+            fn from(value: cgmath::$struct<T>) -> Self {
+                Self { $( $field: value.$field ), + }
+            }
+        }
+
+        impl<T> From<$struct<T>> for cgmath::$struct<T> {
+            #[inline]
+            fn from(value: $struct<T>) -> Self {
+                Self { $( $field: value.$field ), + }
+            }
+        }
+    };
+}
+
+
+impl_cgmath!(Vector2 { x, y });
+impl_cgmath!(Vector3 { x, y, z });
+impl_cgmath!(Vector4 { x, y, z, w });
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::{Vector2, Vector3, Vector3A, Vector4};
+
+
+macro_rules! impl_glam {
+    ( $struct: ident $fields: tt, $($glam: ident: $type: ty), + ) => {
+        $( impl_glam!( @type $struct $fields, $glam, $type ); ) +
+    };
+
+    ( @type $struct: ident { $($field: ident), + }, $glam: ident, $type: ty ) => {
+        impl From<glam::$glam> for $struct<$type> {
+            #[inline]
+            fn from(value: glam::$glam) -> Self {
+                Self { $( $field: value.$field ), + }
+            }
+        }
+
+        impl From<$struct<$type>> for glam::$glam {
+            #[inline]
+            fn from(value: $struct<$type>) -> Self {
+                Self::new($( value.$field ), +)
+            }
+        }
+    };
+}
+
+
+impl_glam!(Vector2 { x, y }, Vec2: f32, DVec2: f64, IVec2: i32, UVec2: u32, I64Vec2: i64, U64Vec2: u64);
+impl_glam!(Vector3 { x, y, z }, Vec3: f32, DVec3: f64, IVec3: i32, UVec3: u32, I64Vec3: i64, U64Vec3: u64);
+impl_glam!(Vector4 { x, y, z, w }, Vec4: f32, DVec4: f64, IVec4: i32, UVec4: u32, I64Vec4: i64, U64Vec4: u64);
+impl_glam!(Vector3A { x, y, z }, Vec3A: f32);
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::{Vector2, Vector3, Vector4};
+
+
+macro_rules! impl_mint {
+    ( $struct: ident { $($field: ident), + } ) => {
+        impl<T> From<mint::$struct<T>> for $struct<T> {
+            #[inline]
+            fn from(value: mint::$struct<T>) -> Self {
+                Self { $( $field: value.$field ), + }
+            }
+        }
+
+        impl<T> From<$struct<T>> for mint::$struct<T> {
+            #[inline]
+            fn from(value: $struct<T>) -> Self {
+                Self { $( $field: value.$field ), + }
+            }
+        }
+
+        impl<T> mint::IntoMint for $struct<T> {
+            type MintType = mint::$struct<T>;
+        }
+    };
+}
+
+
+impl_mint!(Vector2 { x, y });
+impl_mint!(Vector3 { x, y, z });
+impl_mint!(Vector4 { x, y, z, w });
\ No newline at end of file
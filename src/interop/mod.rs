@@ -0,0 +1,20 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "cgmath")]
+mod cgmath;
+
+#[cfg(feature = "glam")]
+mod glam;
+
+#[cfg(feature = "mint")]
+mod mint;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "proptest")]
+mod proptest;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
\ No newline at end of file
@@ -0,0 +1,26 @@
+use crate::{Vector2, Vector3, Vector4};
+use nalgebra::Scalar;
+
+
+macro_rules! impl_nalgebra {
+    ( $struct: ident { $($field: ident), + }, $size: expr ) => {
+        impl<T: Scalar> From<nalgebra::$struct<T>> for $struct<T> {
+            #[inline]
+            fn from(value: nalgebra::$struct<T>) -> Self {
+                Self::from(<[T; $size]>::from(value))
+            }
+        }
+
+        impl<T: Scalar> From<$struct<T>> for nalgebra::$struct<T> {
+            #[inline]
+            fn from(value: $struct<T>) -> Self {
+                Self::new($( value.$field ), +)
+            }
+        }
+    };
+}
+
+
+impl_nalgebra!(Vector2 { x, y }, 2);
+impl_nalgebra!(Vector3 { x, y, z }, 3);
+impl_nalgebra!(Vector4 { x, y, z, w }, 4);
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::{Vector2, Vector3, Vector4, Vector3A, Vector4A};
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::strategy::{Map, Strategy};
+
+
+macro_rules! impl_proptest {
+    ( $struct: ident, ( $($generic: ident), + ) ) => {
+        impl<T: Arbitrary> Arbitrary for $struct<T>
+        where
+            T::Parameters: Clone,
+        {
+            type Parameters = T::Parameters;
+            type Strategy = Map<( $( <$generic as Arbitrary>::Strategy ), + ), fn(( $($generic), + )) -> Self>;
+
+            fn arbitrary_with(parameters: Self::Parameters) -> Self::Strategy {
+                ( $( any_with::<$generic>(parameters.clone()) ), + ).prop_map(Self::from)
+            }
+        }
+    };
+}
+
+
+impl_proptest!(Vector2, (T, T));
+impl_proptest!(Vector3, (T, T, T));
+impl_proptest!(Vector4, (T, T, T, T));
+impl_proptest!(Vector3A, (T, T, T));
+impl_proptest!(Vector4A, (T, T, T, T));
\ No newline at end of file
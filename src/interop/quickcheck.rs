@@ -0,0 +1,37 @@
+extern crate alloc;
+
+use crate::{Vector2, Vector3, Vector4, Vector3A, Vector4A};
+use alloc::boxed::Box;
+use quickcheck::{Arbitrary, Gen};
+
+
+macro_rules! impl_quickcheck {
+    ( $struct: ident { $($field: ident), + } ) => {
+        impl<T: Arbitrary> Arbitrary for $struct<T> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Self {
+                    $( $field: T::arbitrary(g) ), +
+                }
+            }
+
+            // NOTE: Shrinks a single field at a time, keeping the others as they were.
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                let shrunk = core::iter::empty();
+
+                $(
+                    let vector = self.clone();
+                    let shrunk = shrunk.chain(self.$field.shrink().map(move |$field| Self { $field, ..vector.clone() }));
+                ) +
+
+                Box::new(shrunk)
+            }
+        }
+    };
+}
+
+
+impl_quickcheck!(Vector2 { x, y });
+impl_quickcheck!(Vector3 { x, y, z });
+impl_quickcheck!(Vector4 { x, y, z, w });
+impl_quickcheck!(Vector3A { x, y, z });
+impl_quickcheck!(Vector4A { x, y, z, w });
\ No newline at end of file
@@ -0,0 +1,160 @@
+use crate::Fixed;
+use num_traits::float::FloatCore;
+
+
+/// Scalar types whose vectors can be ordered by magnitude, used by [`cmp_by_length`](crate::Vector2::cmp_by_length)
+/// and [`sort_by_length`](crate::Vector2::sort_by_length).
+/// 
+/// The squared magnitude is accumulated within [`Wide`](Self::Wide), a type wide enough that it can't overflow
+/// for vectors of up to four fields, such as a 136-bit integer for the primitive integers.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{LengthOrd, Vector2};
+/// 
+/// fn longest<T: LengthOrd>(vectors: &mut [Vector2<T>]) -> Option<Vector2<T>> {
+///     Vector2::sort_by_length(vectors);
+///     vectors.last().copied()
+/// }
+/// 
+/// assert_eq!(longest(&mut [Vector2::new(60_000_i32, 0), Vector2::new(-1, 1)]), Some(Vector2::new(60_000, 0)));
+/// ```
+pub trait LengthOrd: Copy {
+    /// Representation of a squared magnitude, ordered the same way as the magnitudes themselves.
+    type Wide: Ord;
+
+    /// Returns the squared magnitude of the vector with the given `fields`, or [`None`] if it can't be ordered,
+    /// such as when a field is `NaN`.
+    fn wide_length_squared(fields: &[Self]) -> Option<Self::Wide>;
+}
+
+
+// Sums the squares of `values` as a 136-bit integer, split into the number of times the low 128 bits
+// overflowed and the low 128 bits themselves. Every square fits within 128 bits, so each addition overflows
+// at most once.
+#[inline]
+fn wide_sum_of_squares(values: impl Iterator<Item = u64>) -> (u8, u128) {
+    values.fold((0, 0), |(overflows, sum), value| {
+        let (sum, overflowed) = sum.overflowing_add(value as u128 * value as u128);
+        (overflows + overflowed as u8, sum)
+    })
+}
+
+
+macro_rules! impl_length_ord_int {
+    ( unsigned: $($unsigned: ty), +; signed: $($signed: ty), + ) => {
+        $(
+            impl LengthOrd for $unsigned {
+                type Wide = (u8, u128);
+
+                #[inline]
+                fn wide_length_squared(fields: &[Self]) -> Option<Self::Wide> {
+                    Some(wide_sum_of_squares(fields.iter().map(|field| *field as u64)))
+                }
+            }
+        ) +
+
+        $(
+            impl LengthOrd for $signed {
+                type Wide = (u8, u128);
+
+                #[inline]
+                fn wide_length_squared(fields: &[Self]) -> Option<Self::Wide> {
+                    Some(wide_sum_of_squares(fields.iter().map(|field| field.unsigned_abs() as u64)))
+                }
+            }
+        ) +
+    };
+}
+
+
+impl_length_ord_int!(unsigned: u8, u16, u32, u64, usize; signed: i8, i16, i32, i64, isize);
+
+
+impl LengthOrd for f32 {
+    type Wide = u64;
+
+    #[inline]
+    fn wide_length_squared(fields: &[Self]) -> Option<Self::Wide> {
+        // NOTE: Squaring an `f32` within an `f64` is exact and stays far from its limits,
+        // and the bits of positive `f64`s are ordered the same way as their values.
+        let sum: f64 = fields.iter().map(|field| *field as f64 * *field as f64).sum();
+
+        (!sum.is_nan()).then_some(sum.to_bits())
+    }
+}
+
+
+impl LengthOrd for f64 {
+    type Wide = (i32, u64);
+
+    fn wide_length_squared(fields: &[Self]) -> Option<Self::Wide> {
+        if fields.iter().any(|field| field.is_nan()) {
+            return None;
+        }
+
+        let largest = fields.iter().fold(0.0, |largest: f64, field| FloatCore::max(largest, FloatCore::abs(*field)));
+
+        if largest == 0.0 {
+            return Some((i32::MIN, 0));
+        } else if largest.is_infinite() {
+            return Some((i32::MAX, 0));
+        }
+
+        // NOTE: Dividing every field by the power of two just above the largest one is exact and brings the squares
+        // into a range where they can't overflow, after which the sum is split into its exponent and a mantissa.
+        let (_, exponent) = libm::frexp(largest);
+
+        let sum: f64 = fields.iter().map(|field| {
+            let scaled = libm::ldexp(*field, -exponent);
+            scaled * scaled
+        }).sum();
+
+        let (mantissa, sum_exponent) = libm::frexp(sum);
+
+        Some((exponent * 2 + sum_exponent, mantissa.to_bits()))
+    }
+}
+
+
+macro_rules! impl_length_ord_fixed {
+    ( $($int: ty), + ) => {
+        $(
+            impl<const FRAC: u32> LengthOrd for Fixed<$int, FRAC> {
+                type Wide = (u8, u128);
+
+                #[inline]
+                fn wide_length_squared(fields: &[Self]) -> Option<Self::Wide> {
+                    Some(wide_sum_of_squares(fields.iter().map(|field| field.to_bits().unsigned_abs() as u64)))
+                }
+            }
+        ) +
+    };
+}
+
+
+impl_length_ord_fixed!(i16, i32, i64);
+
+
+#[cfg(feature = "half")]
+macro_rules! impl_length_ord_half {
+    ( $($type: ty), + ) => {
+        $(
+            impl LengthOrd for $type {
+                type Wide = u64;
+
+                #[inline]
+                fn wide_length_squared(fields: &[Self]) -> Option<Self::Wide> {
+                    let sum: f64 = fields.iter().map(|field| field.to_f64() * field.to_f64()).sum();
+
+                    (!sum.is_nan()).then_some(sum.to_bits())
+                }
+            }
+        ) +
+    };
+}
+
+
+#[cfg(feature = "half")]
+impl_length_ord_half!(half::f16, half::bf16);
\ No newline at end of file
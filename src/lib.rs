@@ -2,6 +2,50 @@
 #![no_std]
 
 mod macros;
+mod aabb;
+mod affine;
+mod bytes;
+mod fixed;
+mod geometry;
+mod grid;
+mod interop;
+mod length;
+mod matrix;
+mod morton;
+mod parse;
+mod soa;
+mod total_ord;
+
+#[cfg(feature = "rand")]
+mod random;
+
+#[cfg(feature = "simd")]
+mod simd;
+
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+pub use aabb::{Aabb2, Aabb3};
+pub use affine::{Affine2, Affine3};
+pub use bytes::BufferLengthError;
+pub use macros::floating::FloatingPoint;
+pub use fixed::{Fixed, Q8_8, Q16_16, Q32_32};
+pub use geometry::{Circle, Line2, Plane, Polygon, Ray3, RayHit, Segment2, Sphere, Triangle2, Triangle3};
+pub use grid::VectorRange;
+pub use length::LengthOrd;
+pub use matrix::Matrix4;
+pub use parse::ParseVectorError;
+pub use soa::{Vector2Soa, Vector3Soa, Vector4Soa};
+pub use total_ord::TotalOrd;
+
+#[cfg(feature = "rand")]
+pub use random::{InsideUnitDisk, InsideUnitSphere, UnitCircle, UnitSphere};
+
+#[cfg(feature = "rand")]
+pub use random::{UniformVector2, UniformVector3, UniformVector4, UniformVector3A, UniformVector4A};
+
+#[cfg(feature = "simd")]
+pub use simd::{SimdVector3, SimdVector4};
 
 #[cfg(test)]
 mod tests;
@@ -70,6 +114,76 @@ pub struct Vector4<T> {
 }
 
 
+/// Vector for holding three-dimensional values, aligned to 16 bytes for SIMD loads
+/// and GPU buffer layouts such as `std140` and `std430`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Vector3, Vector3A};
+/// 
+/// let mut vec3 = Vector3A::new(1.0_f32, 2.0, 3.0);
+/// vec3 += Vector3A::new(1.0, 2.0, 3.0);
+/// 
+/// assert_eq!(core::mem::align_of_val(&vec3), 16);
+/// assert_eq!(Vector3::from(vec3), Vector3::new(2.0, 4.0, 6.0));
+/// ```
+#[repr(C, align(16))]
+pub struct Vector3A<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+
+/// Vector for holding four-dimensional values, aligned to 16 bytes for SIMD loads
+/// and GPU buffer layouts such as `std140` and `std430`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Vector4, Vector4A};
+/// 
+/// let mut vec4 = Vector4A::new(1.0_f32, 2.0, 3.0, 4.0);
+/// vec4 += Vector4A::new(1.0, 2.0, 3.0, 4.0);
+/// 
+/// assert_eq!(core::mem::align_of_val(&vec4), 16);
+/// assert_eq!(Vector4::from(vec4), Vector4::new(2.0, 4.0, 6.0, 8.0));
+/// ```
+#[repr(C, align(16))]
+pub struct Vector4A<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+
 impl_vector!(Vector2 { x, y }, (T, T), 2);
 impl_vector!(Vector3 { x, y, z }, (T, T, T), 3);
-impl_vector!(Vector4 { x, y, z, w }, (T, T, T, T), 4);
\ No newline at end of file
+impl_vector!(Vector4 { x, y, z, w }, (T, T, T, T), 4);
+impl_vector!(Vector3A { x, y, z }, (T, T, T), 3);
+impl_vector!(Vector4A { x, y, z, w }, (T, T, T, T), 4);
+
+
+impl_constants!(Vector2 { x, y }, { X: x, Y: y }, { NEG_X: x, NEG_Y: y });
+impl_constants!(Vector3 { x, y, z }, { X: x, Y: y, Z: z }, { NEG_X: x, NEG_Y: y, NEG_Z: z });
+impl_constants!(Vector4 { x, y, z, w }, { X: x, Y: y, Z: z, W: w }, { NEG_X: x, NEG_Y: y, NEG_Z: z, NEG_W: w });
+impl_constants!(Vector3A { x, y, z }, { X: x, Y: y, Z: z }, { NEG_X: x, NEG_Y: y, NEG_Z: z });
+impl_constants!(Vector4A { x, y, z, w }, { X: x, Y: y, Z: z, W: w }, { NEG_X: x, NEG_Y: y, NEG_Z: z, NEG_W: w });
+
+
+impl_bytes!(Vector2 { x, y }, 2);
+impl_bytes!(Vector3 { x, y, z }, 3);
+impl_bytes!(Vector4 { x, y, z, w }, 4);
+impl_bytes!(Vector3A { x, y, z }, 3);
+impl_bytes!(Vector4A { x, y, z, w }, 4);
+
+
+impl_perp!(Vector2);
+impl_cross!(Vector3);
+impl_cross!(Vector3A);
+
+
+impl_aligned_conversions!(Vector3A, Vector3 { x, y, z });
+impl_aligned_conversions!(Vector4A, Vector4 { x, y, z, w });
\ No newline at end of file
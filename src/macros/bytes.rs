@@ -0,0 +1,162 @@
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_bytes {
+    ( $struct: ident { $($field: ident), + }, $size: expr ) => {
+        $crate::impl_bytes!(
+            @types $struct { $($field), + }, $size,
+            u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64
+        );
+    };
+
+    ( @types $struct: ident $fields: tt, $size: expr, $($type: ty), + ) => {
+        $( $crate::impl_bytes!( @type $struct $fields, $size, $type ); ) +
+    };
+
+    ( @type $struct: ident { $($field: ident), + }, $size: expr, $type: ty ) => {
+        impl $struct<$type> {
+            /// Returns the memory representation of the vector as a byte array, with each field
+            /// in little-endian byte order and the fields in declaration order.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let bytes = Vector2::new(0x0102_u16, 0x0304).to_le_bytes();
+            /// 
+            /// assert_eq!(bytes, [0x02, 0x01, 0x04, 0x03]);
+            /// assert_eq!(Vector2::<u16>::from_le_bytes(bytes), Vector2::new(0x0102, 0x0304));
+            /// ```
+            #[inline]
+            pub fn to_le_bytes(self) -> [u8; $size * core::mem::size_of::<$type>()] {
+                $crate::impl_bytes!( @encode self, $size, $type, to_le_bytes, { $($field), + } )
+            }
+
+            /// Returns the memory representation of the vector as a byte array, with each field
+            /// in big-endian byte order and the fields in declaration order.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let bytes = Vector2::new(0x0102_u16, 0x0304).to_be_bytes();
+            /// 
+            /// assert_eq!(bytes, [0x01, 0x02, 0x03, 0x04]);
+            /// assert_eq!(Vector2::<u16>::from_be_bytes(bytes), Vector2::new(0x0102, 0x0304));
+            /// ```
+            #[inline]
+            pub fn to_be_bytes(self) -> [u8; $size * core::mem::size_of::<$type>()] {
+                $crate::impl_bytes!( @encode self, $size, $type, to_be_bytes, { $($field), + } )
+            }
+
+            /// Returns the memory representation of the vector as a byte array, with each field
+            /// in native byte order and the fields in declaration order.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let bytes = Vector2::new(0x0102_u16, 0x0304).to_ne_bytes();
+            /// 
+            /// assert_eq!(Vector2::<u16>::from_ne_bytes(bytes), Vector2::new(0x0102, 0x0304));
+            /// ```
+            #[inline]
+            pub fn to_ne_bytes(self) -> [u8; $size * core::mem::size_of::<$type>()] {
+                $crate::impl_bytes!( @encode self, $size, $type, to_ne_bytes, { $($field), + } )
+            }
+
+            /// Constructs a vector from its representation as a byte array in little-endian byte order.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; $size * core::mem::size_of::<$type>()]) -> Self {
+                $crate::impl_bytes!( @decode bytes, $size, $type, from_le_bytes )
+            }
+
+            /// Constructs a vector from its representation as a byte array in big-endian byte order.
+            #[inline]
+            pub fn from_be_bytes(bytes: [u8; $size * core::mem::size_of::<$type>()]) -> Self {
+                $crate::impl_bytes!( @decode bytes, $size, $type, from_be_bytes )
+            }
+
+            /// Constructs a vector from its representation as a byte array in native byte order.
+            #[inline]
+            pub fn from_ne_bytes(bytes: [u8; $size * core::mem::size_of::<$type>()]) -> Self {
+                $crate::impl_bytes!( @decode bytes, $size, $type, from_ne_bytes )
+            }
+
+            /// Writes the vector to the start of `buffer` in little-endian byte order, returning the number
+            /// of bytes written, or a [`BufferLengthError`](crate::BufferLengthError) if `buffer` is too short.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let mut buffer = [0; 6];
+            /// 
+            /// assert_eq!(Vector2::new(0x0102_u16, 0x0304).write_to(&mut buffer), Ok(4));
+            /// assert_eq!(buffer, [0x02, 0x01, 0x04, 0x03, 0x00, 0x00]);
+            /// ```
+            pub fn write_to(self, buffer: &mut [u8]) -> Result<usize, $crate::BufferLengthError> {
+                let bytes = self.to_le_bytes();
+                let found = buffer.len();
+
+                let destination = buffer.get_mut(..bytes.len()).ok_or($crate::BufferLengthError {
+                    required: bytes.len(),
+                    found,
+                })?;
+
+                destination.copy_from_slice(&bytes);
+                Ok(bytes.len())
+            }
+
+            /// Reads a vector from the start of `buffer` in little-endian byte order,
+            /// or returns a [`BufferLengthError`](crate::BufferLengthError) if `buffer` is too short.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let buffer = [0x02, 0x01, 0x04, 0x03, 0xFF];
+            /// 
+            /// assert_eq!(Vector2::<u16>::read_from(&buffer), Ok(Vector2::new(0x0102, 0x0304)));
+            /// assert!(Vector2::<u32>::read_from(&buffer).is_err());
+            /// ```
+            pub fn read_from(buffer: &[u8]) -> Result<Self, $crate::BufferLengthError> {
+                let mut bytes = [0; $size * core::mem::size_of::<$type>()];
+
+                let source = buffer.get(..bytes.len()).ok_or($crate::BufferLengthError {
+                    required: bytes.len(),
+                    found: buffer.len(),
+                })?;
+
+                bytes.copy_from_slice(source);
+                Ok(Self::from_le_bytes(bytes))
+            }
+        }
+    };
+
+    ( @encode $vector: ident, $size: expr, $type: ty, $method: ident, { $($field: ident), + } ) => {{
+        let mut bytes = [0; $size * core::mem::size_of::<$type>()];
+        let fields = [ $( $vector.$field ), + ];
+
+        for (chunk, field) in bytes.chunks_exact_mut(core::mem::size_of::<$type>()).zip(fields) {
+            chunk.copy_from_slice(&field.$method());
+        }
+
+        bytes
+    }};
+
+    ( @decode $bytes: ident, $size: expr, $type: ty, $method: ident ) => {
+        Self::from(core::array::from_fn::<$type, $size, _>(|index| {
+            const WIDTH: usize = core::mem::size_of::<$type>();
+
+            let mut field = [0; WIDTH];
+            field.copy_from_slice(&$bytes[(index * WIDTH)..][..WIDTH]);
+
+            <$type>::$method(field)
+        }))
+    };
+}
\ No newline at end of file
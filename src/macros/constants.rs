@@ -0,0 +1,100 @@
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_constants {
+    ( $struct: ident $fields: tt, $axes: tt, $negative_axes: tt ) => {
+        $crate::impl_constants!( @unsigned $struct $fields, $axes, u8, u16, u32, u64, u128, usize );
+        $crate::impl_constants!( @signed $struct $fields, $axes, $negative_axes, i8, i16, i32, i64, i128, isize );
+        $crate::impl_constants!( @float $struct $fields, $axes, $negative_axes, f32, f64 );
+        $crate::impl_constants!( @traits $struct $fields );
+    };
+
+    ( @unsigned $struct: ident $fields: tt, $axes: tt, $($type: ty), + ) => {
+        $( $crate::impl_constants!( @common $struct $fields, $axes, $type ); ) +
+    };
+
+    ( @signed $struct: ident $fields: tt, $axes: tt, $negative_axes: tt, $($type: ty), + ) => {
+        $(
+            $crate::impl_constants!( @common $struct $fields, $axes, $type );
+            $crate::impl_constants!( @negative $struct $fields, $negative_axes, $type );
+        ) +
+    };
+
+    ( @float $struct: ident $fields: tt, $axes: tt, $negative_axes: tt, $($type: ty), + ) => {
+        $(
+            $crate::impl_constants!( @common $struct $fields, $axes, $type );
+            $crate::impl_constants!( @negative $struct $fields, $negative_axes, $type );
+
+            impl $struct<$type> {
+                /// A vector with all fields set to `NaN`.
+                pub const NAN: Self = Self::from_value(<$type>::NAN);
+
+                /// A vector with all fields set to positive infinity.
+                pub const INFINITY: Self = Self::from_value(<$type>::INFINITY);
+
+                /// A vector with all fields set to negative infinity.
+                pub const NEG_INFINITY: Self = Self::from_value(<$type>::NEG_INFINITY);
+
+                /// A vector with all fields set to the machine epsilon of the field type.
+                pub const EPSILON: Self = Self::from_value(<$type>::EPSILON);
+            }
+        ) +
+    };
+
+    ( @common $struct: ident { $($field: ident), + }, { $($axis: ident: $axis_field: ident), + }, $type: ty ) => {
+        impl $struct<$type> {
+            /// A vector with all fields set to `0`.
+            pub const ZERO: Self = Self::from_value(0 as $type);
+
+            /// A vector with all fields set to `1`.
+            pub const ONE: Self = Self::from_value(1 as $type);
+
+            /// A vector with all fields set to the smallest value of the field type.
+            pub const MIN: Self = Self::from_value(<$type>::MIN);
+
+            /// A vector with all fields set to the largest value of the field type.
+            pub const MAX: Self = Self::from_value(<$type>::MAX);
+
+            $(
+                #[doc = core::concat!("A unit vector pointing along the positive `", core::stringify!($axis_field), "` axis.")]
+                pub const $axis: Self = Self { $axis_field: 1 as $type, ..Self::ZERO };
+            ) +
+        }
+    };
+
+    ( @negative $struct: ident { $($field: ident), + }, { $($axis: ident: $axis_field: ident), + }, $type: ty ) => {
+        impl $struct<$type> {
+            /// A vector with all fields set to `-1`.
+            pub const NEG_ONE: Self = Self::from_value(-1 as $type);
+
+            $(
+                #[doc = core::concat!("A unit vector pointing along the negative `", core::stringify!($axis_field), "` axis.")]
+                pub const $axis: Self = Self { $axis_field: -1 as $type, ..Self::ZERO };
+            ) +
+        }
+    };
+
+    ( @traits $struct: ident { $($field: ident), + } ) => {
+        impl<T: num_traits::Zero> num_traits::Zero for $struct<T> {
+            #[inline]
+            fn zero() -> Self {
+                Self {
+                    $( $field: T::zero() ), +
+                }
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                $( self.$field.is_zero() ) && +
+            }
+        }
+
+        impl<T: num_traits::One> num_traits::One for $struct<T> {
+            #[inline]
+            fn one() -> Self {
+                Self {
+                    $( $field: T::one() ), +
+                }
+            }
+        }
+    };
+}
@@ -164,6 +164,23 @@ macro_rules! impl_floating_point_operations {
                 self.map(|f| f.powi(n))
             }
 
+            /// Applies [`recip`](num_traits::float::FloatCore::recip) on all fields within the vector,
+            /// converting each field's value to its reciprocal, `1.0 / value`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(2.0, 4.0).recip();
+            /// 
+            /// assert_eq!(vec2, Vector2::new(0.5, 0.25));
+            /// ```
+            #[inline]
+            pub fn recip(self) -> Self {
+                self.map(T::recip)
+            }
+
             /// Linearly interpolates between two Vectors by a normalized `weight`.
             /// 
             /// # Example
@@ -183,11 +200,59 @@ macro_rules! impl_floating_point_operations {
                     $( $field: self.$field + (weight * (to.$field - self.$field)) ), +
                 }
             }
+
+            /// Returns `true` if all fields within the vector are neither infinite nor `NaN`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// assert!(Vector2::new(1.0, 2.0).is_finite());
+            /// assert!(!Vector2::new(1.0, f32::INFINITY).is_finite());
+            /// ```
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                $( self.$field.is_finite() ) && +
+            }
+
+            /// Returns `true` if any field within the vector is `NaN`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// assert!(Vector2::new(f32::NAN, 2.0).is_nan());
+            /// assert!(!Vector2::new(1.0, 2.0).is_nan());
+            /// ```
+            #[inline]
+            pub fn is_nan(&self) -> bool {
+                $( self.$field.is_nan() ) || +
+            }
+
+            /// Returns a vector of booleans describing which fields within the vector are `NaN`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let mask = Vector2::new(f32::NAN, 2.0).is_nan_mask();
+            /// 
+            /// assert_eq!(mask, Vector2::new(true, false));
+            /// ```
+            #[inline]
+            pub fn is_nan_mask(&self) -> $struct<bool> {
+                $struct {
+                    $( $field: self.$field.is_nan() ), +
+                }
+            }
         }
 
         impl<T> $struct<T>
         where
-            T: $crate::macros::floating::_FloatingPoint
+            T: $crate::macros::floating::FloatingPoint
             + num_traits::float::FloatCore
         {
             /// Consumes the vector and returns it with all of its fields converted to their square-root.
@@ -206,6 +271,105 @@ macro_rules! impl_floating_point_operations {
                 self.map(T::sqrt)
             }
 
+            /// Computes `(self * a) + b` on all fields within the vector with only one rounding error,
+            /// yielding a more accurate result than an unfused multiply-add.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(2.0, 3.0).mul_add(
+            ///     Vector2::new(4.0, 5.0), Vector2::new(1.0, 1.0)
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(9.0, 16.0));
+            /// ```
+            #[inline]
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                Self {
+                    $( $field: self.$field.mul_add(a.$field, b.$field) ), +
+                }
+            }
+
+            /// Returns the dot product of two vectors, accumulated through fused multiply-adds.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let a = Vector2::new(1.0, 2.0);
+            /// let b = Vector2::new(2.0, 4.0);
+            /// let dot = a.dot_fma(&b);
+            /// 
+            /// assert_eq!(dot, 10.0);
+            /// ```
+            #[inline]
+            pub fn dot_fma(&self, other: &Self) -> T {
+                let mut sum = T::zero();
+                $( sum = self.$field.mul_add(other.$field, sum); ) +
+                sum
+            }
+
+            /// Returns a vector with the magnitude of each field within this vector
+            /// and the sign of the matching field within `sign`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(2.0, -3.0).copysign(
+            ///     Vector2::new(-1.0, 1.0)
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(-2.0, 3.0));
+            /// ```
+            #[inline]
+            pub fn copysign(self, sign: Self) -> Self {
+                Self {
+                    $( $field: self.$field.copysign(sign.$field) ), +
+                }
+            }
+
+            /// Calculates the least nonnegative remainder of each field within the vector
+            /// divided by the matching field within `rhs`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(-7.0, 7.0).rem_euclid(
+            ///     Vector2::new(4.0, -4.0)
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(1.0, 3.0));
+            /// ```
+            #[inline]
+            pub fn rem_euclid(self, rhs: Self) -> Self {
+                Self {
+                    $( $field: self.$field.rem_euclid(rhs.$field) ), +
+                }
+            }
+
+            /// Consumes the vector and returns it with all of its fields converted to `2` raised to their power.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(3.0, -1.0).exp2();
+            /// 
+            /// assert_eq!(vec2, Vector2::new(8.0, 0.5));
+            /// ```
+            #[inline]
+            pub fn exp2(self) -> Self {
+                self.map(T::exp2)
+            }
+
             /// Returns the magnitude of the vector.
             /// 
             /// # Example
@@ -218,9 +382,31 @@ macro_rules! impl_floating_point_operations {
             /// 
             /// assert!(length < 4.2);
             /// ```
-            #[inline]
             pub fn length(&self) -> T {
-                self.length_squared().sqrt()
+                let length_squared = self.length_squared();
+
+                if length_squared.is_infinite() || length_squared == T::zero() {
+                    return self.scaled_length();
+                }
+
+                length_squared.sqrt()
+            }
+
+            // Returns the magnitude of the vector after dividing every field by the largest one, so the
+            // squared fields can't over- or underflow, such as with fixed-point fields longer than `sqrt(MAX)`.
+            fn scaled_length(&self) -> T {
+                let mut scale = T::zero();
+                $( scale = scale.max(self.$field.abs()); ) +
+
+                if scale == T::zero() || scale.is_infinite() {
+                    return scale;
+                }
+
+                let scaled = Self {
+                    $( $field: self.$field / scale ), +
+                };
+
+                scaled.length_squared().sqrt() * scale
             }
 
             /// Consumes the vector and returns it as normalized vector.
@@ -236,14 +422,12 @@ macro_rules! impl_floating_point_operations {
             /// assert!(vec2.y < 1.0);
             /// ```
             pub fn normalized(self) -> Self {
-                let length_squared = self.length_squared();
+                let length = self.length();
 
-                if length_squared == T::zero() {
+                if length == T::zero() {
                     return Self { $( $field: T::zero() ), + };
                 }
 
-                let length = length_squared.sqrt();
-
                 Self {
                     $( $field: self.$field / length ), +
                 }
@@ -262,18 +446,231 @@ macro_rules! impl_floating_point_operations {
             /// assert!(vec2.x < 1.0);
             /// assert!(vec2.y < 1.0);
             /// ```
+            #[inline]
             pub fn normalize(&mut self) {
-                let length_squared = self.length_squared();
+                *self = self.normalized();
+            }
+
+            /// Returns the reciprocal of the vector's magnitude, `1.0 / length`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let recip = Vector2::new(3.0, 4.0).length_recip();
+            /// 
+            /// assert_eq!(recip, 0.2);
+            /// ```
+            #[inline]
+            pub fn length_recip(&self) -> T {
+                T::one() / self.length()
+            }
+
+            /// Returns `true` if the vector has a magnitude of `1.0`, within a small tolerance.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// assert!(Vector2::new(0.6, 0.8).is_normalized());
+            /// assert!(!Vector2::new(3.0, 4.0).is_normalized());
+            /// ```
+            #[inline]
+            pub fn is_normalized(&self) -> bool {
+                let four = T::one() + T::one() + T::one() + T::one();
 
-                if length_squared == T::zero() {
-                    *self = Self { $( $field: T::zero() ), + };
-                    return;
+                // NOTE: Low precision types such as `f16` can't represent a tolerance of `2e-4`
+                // around `1.0`, so the tolerance is widened to a few units of their epsilon.
+                let tolerance = num_traits::cast::<f64, T>(2e-4)
+                    .unwrap_or_else(T::epsilon)
+                    .max(T::epsilon() * four);
+
+                (self.length_squared() - T::one()).abs() <= tolerance
+            }
+
+            /// Consumes the vector and returns it normalized, or [`None`] if the vector
+            /// has a magnitude of zero, is infinite, or contains `NaN`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// assert_eq!(Vector2::new(0.0, 4.0).try_normalize(), Some(Vector2::new(0.0, 1.0)));
+            /// assert_eq!(Vector2::new(0.0, 0.0).try_normalize(), None);
+            /// assert_eq!(Vector2::new(f32::NAN, 1.0).try_normalize(), None);
+            /// ```
+            pub fn try_normalize(self) -> Option<Self> {
+                let length = self.length();
+
+                if length.is_finite() && length > T::zero() {
+                    return Some(Self {
+                        $( $field: self.$field / length ), +
+                    });
                 }
 
-                let length = length_squared.sqrt();
+                None
+            }
 
-                *self = Self {
-                    $( $field: self.$field / length ), +
+            /// Consumes the vector and returns it normalized, or `fallback` if the vector
+            /// has a magnitude of zero, is infinite, or contains `NaN`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let fallback = Vector2::new(1.0, 0.0);
+            /// let vec2 = Vector2::new(0.0, 0.0).normalize_or(fallback);
+            /// 
+            /// assert_eq!(vec2, fallback);
+            /// ```
+            #[inline]
+            pub fn normalize_or(self, fallback: Self) -> Self {
+                self.try_normalize().unwrap_or(fallback)
+            }
+
+            /// Consumes the vector and returns it normalized, or a zeroed vector if the vector
+            /// has a magnitude of zero, is infinite, or contains `NaN`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(f32::NAN, 1.0).normalize_or_zero();
+            /// 
+            /// assert_eq!(vec2, Vector2::new(0.0, 0.0));
+            /// ```
+            #[inline]
+            pub fn normalize_or_zero(self) -> Self {
+                self.normalize_or(Self { $( $field: T::zero() ), + })
+            }
+
+            /// Consumes the vector and returns it scaled to the given `length`, keeping its direction.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(3.0, 4.0).with_length(10.0);
+            /// 
+            /// assert_eq!(vec2, Vector2::new(6.0, 8.0));
+            /// ```
+            #[inline]
+            pub fn with_length(self, length: T) -> Self {
+                self.normalized() * length
+            }
+
+            /// Consumes the vector and returns it with its magnitude clamped between `min` and `max`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(3.0, 4.0).clamp_length(1.0, 2.5);
+            /// 
+            /// assert_eq!(vec2, Vector2::new(1.5, 2.0));
+            /// ```
+            pub fn clamp_length(self, min: T, max: T) -> Self {
+                let length = self.length();
+
+                if length < min {
+                    return self.with_length(min);
+                }
+
+                if length > max {
+                    return self.with_length(max);
+                }
+
+                self
+            }
+
+            /// Consumes the vector and returns it with its magnitude no greater than `max`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(3.0, 4.0).clamp_length_max(2.5);
+            /// 
+            /// assert_eq!(vec2, Vector2::new(1.5, 2.0));
+            /// ```
+            pub fn clamp_length_max(self, max: T) -> Self {
+                if self.length() > max {
+                    return self.with_length(max);
+                }
+
+                self
+            }
+
+            /// Consumes the vector and returns it with its magnitude no less than `min`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(3.0, 4.0).clamp_length_min(10.0);
+            /// 
+            /// assert_eq!(vec2, Vector2::new(6.0, 8.0));
+            /// ```
+            pub fn clamp_length_min(self, min: T) -> Self {
+                if self.length() < min {
+                    return self.with_length(min);
+                }
+
+                self
+            }
+
+            /// Consumes the vector and returns it with its magnitude limited to `max`,
+            /// equivalent to [`clamp_length_max`](Self::clamp_length_max).
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(0.0, 8.0).limit(2.0);
+            /// 
+            /// assert_eq!(vec2, Vector2::new(0.0, 2.0));
+            /// ```
+            #[inline]
+            pub fn limit(self, max: T) -> Self {
+                self.clamp_length_max(max)
+            }
+
+            /// Moves the vector towards `target` by at most `max_delta`, without overshooting it.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(0.0, 0.0).move_towards(Vector2::new(0.0, 10.0), 4.0);
+            /// assert_eq!(vec2, Vector2::new(0.0, 4.0));
+            /// 
+            /// let vec2 = vec2.move_towards(Vector2::new(0.0, 5.0), 4.0);
+            /// assert_eq!(vec2, Vector2::new(0.0, 5.0));
+            /// ```
+            pub fn move_towards(self, target: Self, max_delta: T) -> Self {
+                let delta = Self {
+                    $( $field: target.$field - self.$field ), +
+                };
+
+                let distance = delta.length();
+
+                if distance <= max_delta || distance <= T::epsilon() {
+                    return target;
+                }
+
+                Self {
+                    $( $field: self.$field + (delta.$field / distance * max_delta) ), +
                 }
             }
         }
@@ -292,24 +689,110 @@ macro_rules! sum_repeating {
 }
 
 
-// Required trait for `sqrt` impls
-#[doc(hidden)]
-pub trait _FloatingPoint {
+/// Floating-point operations required for [`sqrt`](crate::Vector2::sqrt), [`length`](crate::Vector2::length),
+/// [`normalized`](crate::Vector2::normalized) and the trigonometric and fused arithmetic functions on vectors.
+/// 
+/// Implemented for every [`num_traits::Float`], which includes `f32`, `f64` and, with the `half` feature,
+/// `half::f16` and `half::bf16`. Custom scalar types can implement it directly.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{FloatingPoint, Vector2};
+/// 
+/// fn magnitude<T: FloatingPoint + num_traits::float::FloatCore>(vec2: Vector2<T>) -> T {
+///     vec2.length()
+/// }
+/// 
+/// assert_eq!(magnitude(Vector2::new(3.0, 4.0)), 5.0);
+/// ```
+pub trait FloatingPoint {
+    /// Returns the square root of the number.
     fn sqrt(self) -> Self;
+
+    /// Returns the sine of the number, in radians.
+    fn sin(self) -> Self;
+
+    /// Returns the cosine of the number, in radians.
+    fn cos(self) -> Self;
+
+    /// Returns the arcsine of the number, in radians.
+    fn asin(self) -> Self;
+
+    /// Returns the arccosine of the number, in radians.
+    fn acos(self) -> Self;
+
+    /// Returns the four quadrant arctangent of `self` and `other`, in radians.
+    fn atan2(self, other: Self) -> Self;
+
+    /// Computes `(self * a) + b` with a single rounding error.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Returns the number with the magnitude of `self` and the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+
+    /// Returns the least non-negative remainder of `self` divided by `rhs`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Returns `2` raised to the power of the number.
+    fn exp2(self) -> Self;
 }
 
 
-impl _FloatingPoint for f32 {
+impl<T: num_traits::Float> FloatingPoint for T {
     #[inline(always)]
     fn sqrt(self) -> Self {
-        libm::sqrtf(self)
+        num_traits::Float::sqrt(self)
     }
-}
 
+    #[inline(always)]
+    fn sin(self) -> Self {
+        num_traits::Float::sin(self)
+    }
 
-impl _FloatingPoint for f64 {
     #[inline(always)]
-    fn sqrt(self) -> Self {
-        libm::sqrt(self)
+    fn cos(self) -> Self {
+        num_traits::Float::cos(self)
+    }
+
+    #[inline(always)]
+    fn asin(self) -> Self {
+        num_traits::Float::asin(self)
+    }
+
+    #[inline(always)]
+    fn acos(self) -> Self {
+        num_traits::Float::acos(self)
+    }
+
+    #[inline(always)]
+    fn atan2(self, other: Self) -> Self {
+        num_traits::Float::atan2(self, other)
+    }
+
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        num_traits::Float::mul_add(self, a, b)
+    }
+
+    #[inline(always)]
+    fn copysign(self, sign: Self) -> Self {
+        num_traits::Float::copysign(self, sign)
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let remainder = self % rhs;
+
+        if remainder < T::zero() {
+            return remainder + num_traits::Float::abs(rhs);
+        }
+
+        remainder
+    }
+
+    #[inline(always)]
+    fn exp2(self) -> Self {
+        num_traits::Float::exp2(self)
     }
 }
\ No newline at end of file
@@ -0,0 +1,336 @@
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_interpolation {
+    ( $struct: ident { $($field: ident), + } ) => {
+        impl<T: num_traits::float::FloatCore> $struct<T> {
+            /// Applies a smooth Hermite step on all fields within the vector, returning `0.0` for fields
+            /// at or below `edge0`, `1.0` for fields at or above `edge1` and a smooth transition in between.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(-1.0, 0.5).smoothstep(
+            ///     Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(0.0, 0.5));
+            /// ```
+            pub fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+                let two = T::one() + T::one();
+                let three = two + T::one();
+
+                Self {
+                    $(
+                        $field: {
+                            let t = ((self.$field - edge0.$field) / (edge1.$field - edge0.$field))
+                                .max(T::zero())
+                                .min(T::one());
+
+                            t * t * (three - (two * t))
+                        }
+                    ), +
+                }
+            }
+
+            /// Applies Ken Perlin's smoother step on all fields within the vector, which unlike
+            /// [`smoothstep`](Self::smoothstep) also has zero second-order derivatives at the edges.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(2.0, 0.5).smootherstep(
+            ///     Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(1.0, 0.5));
+            /// ```
+            pub fn smootherstep(self, edge0: Self, edge1: Self) -> Self {
+                let two = T::one() + T::one();
+                let five = two + two + T::one();
+                let six = five + T::one();
+                let ten = five + five;
+                let fifteen = ten + five;
+
+                Self {
+                    $(
+                        $field: {
+                            let t = ((self.$field - edge0.$field) / (edge1.$field - edge0.$field))
+                                .max(T::zero())
+                                .min(T::one());
+
+                            t * t * t * ((t * ((t * six) - fifteen)) + ten)
+                        }
+                    ), +
+                }
+            }
+
+            /// Returns the weights that would [`lerp`](Self::lerp) from this vector to `to` and produce `value`,
+            /// computed on each field individually.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let weights = Vector2::new(0.0, 10.0).inverse_lerp(
+            ///     Vector2::new(4.0, 20.0), Vector2::new(1.0, 15.0)
+            /// );
+            /// 
+            /// assert_eq!(weights, Vector2::new(0.25, 0.5));
+            /// ```
+            #[inline]
+            pub fn inverse_lerp(self, to: Self, value: Self) -> Self {
+                Self {
+                    $( $field: (value.$field - self.$field) / (to.$field - self.$field) ), +
+                }
+            }
+
+            /// Maps all fields within the vector from the range `in_min..in_max` onto the range `out_min..out_max`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(0.5, 0.25).remap(
+            ///     Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0),
+            ///     Vector2::new(0.0, 0.0), Vector2::new(10.0, 100.0),
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(5.0, 25.0));
+            /// ```
+            #[inline]
+            pub fn remap(self, in_min: Self, in_max: Self, out_min: Self, out_max: Self) -> Self {
+                let weights = in_min.inverse_lerp(in_max, self);
+
+                Self {
+                    $( $field: out_min.$field + (weights.$field * (out_max.$field - out_min.$field)) ), +
+                }
+            }
+
+            /// Evaluates the quadratic Bézier curve starting at this vector and ending at `to`, at the given `weight`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(0.0, 0.0).quadratic_bezier(
+            ///     Vector2::new(1.0, 2.0), Vector2::new(2.0, 0.0), 0.5
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(1.0, 1.0));
+            /// ```
+            pub fn quadratic_bezier(self, control: Self, to: Self, weight: T) -> Self {
+                let two = T::one() + T::one();
+                let inverse = T::one() - weight;
+
+                (self * (inverse * inverse))
+                    + (control * (two * inverse * weight))
+                    + (to * (weight * weight))
+            }
+
+            /// Evaluates the cubic Bézier curve starting at this vector and ending at `to`, at the given `weight`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(0.0, 0.0).cubic_bezier(
+            ///     Vector2::new(0.0, 4.0), Vector2::new(4.0, 4.0), Vector2::new(4.0, 0.0), 0.5
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(2.0, 3.0));
+            /// ```
+            pub fn cubic_bezier(self, control_1: Self, control_2: Self, to: Self, weight: T) -> Self {
+                let three = T::one() + T::one() + T::one();
+                let inverse = T::one() - weight;
+
+                (self * (inverse * inverse * inverse))
+                    + (control_1 * (three * inverse * inverse * weight))
+                    + (control_2 * (three * inverse * weight * weight))
+                    + (to * (weight * weight * weight))
+            }
+
+            /// Evaluates the cubic Hermite curve from this vector to `to`, leaving this vector along `tangent_from`
+            /// and arriving at `to` along `tangent_to`, at the given `weight`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(0.0, 0.0).hermite(
+            ///     Vector2::new(1.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(1.0, 0.0), 0.5
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(1.0, 0.0));
+            /// ```
+            pub fn hermite(self, tangent_from: Self, to: Self, tangent_to: Self, weight: T) -> Self {
+                let two = T::one() + T::one();
+                let three = two + T::one();
+
+                let weight_squared = weight * weight;
+                let weight_cubed = weight_squared * weight;
+
+                let from_coefficient = (two * weight_cubed) - (three * weight_squared) + T::one();
+                let tangent_from_coefficient = weight_cubed - (two * weight_squared) + weight;
+                let to_coefficient = (three * weight_squared) - (two * weight_cubed);
+                let tangent_to_coefficient = weight_cubed - weight_squared;
+
+                (self * from_coefficient)
+                    + (tangent_from * tangent_from_coefficient)
+                    + (to * to_coefficient)
+                    + (tangent_to * tangent_to_coefficient)
+            }
+
+            /// Evaluates the Catmull-Rom spline segment between this vector and `to` at the given `weight`,
+            /// using `pre` and `post` as the points before and after the segment.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(1.0, 0.0).catmull_rom(
+            ///     Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(3.0, 0.0), 0.5
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(1.5, 0.0));
+            /// ```
+            pub fn catmull_rom(self, pre: Self, to: Self, post: Self, weight: T) -> Self {
+                let half = T::one() / (T::one() + T::one());
+                let tangent_from = (to - pre) * half;
+                let tangent_to = (post - self) * half;
+
+                self.hermite(tangent_from, to, tangent_to, weight)
+            }
+
+            /// Evaluates the Catmull-Rom spline passing through every vector in `points` at the given `weight`,
+            /// where `0.0` is the first point and `1.0` is the last point. Returns [`None`] if `points` is empty.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let points = [
+            ///     Vector2::new(0.0, 0.0),
+            ///     Vector2::new(1.0, 0.0),
+            ///     Vector2::new(2.0, 0.0),
+            /// ];
+            /// 
+            /// assert_eq!(Vector2::catmull_rom_spline(&points, 0.0), Some(Vector2::new(0.0, 0.0)));
+            /// assert_eq!(Vector2::catmull_rom_spline(&points, 0.5), Some(Vector2::new(1.0, 0.0)));
+            /// assert_eq!(Vector2::catmull_rom_spline(&points, 1.0), Some(Vector2::new(2.0, 0.0)));
+            /// ```
+            pub fn catmull_rom_spline(points: &[Self], weight: T) -> Option<Self> {
+                let last = points.len().checked_sub(1)?;
+
+                if last == 0 {
+                    return Some(points[0]);
+                }
+
+                let position = weight.max(T::zero()).min(T::one()) * num_traits::cast(last)?;
+                let segment = num_traits::cast::<T, usize>(position.floor())?.min(last - 1);
+                let local_weight = position - num_traits::cast(segment)?;
+
+                let pre = points[segment.saturating_sub(1)];
+                let post = points[(segment + 2).min(last)];
+
+                Some(points[segment].catmull_rom(pre, points[segment + 1], post, local_weight))
+            }
+        }
+
+        impl<T> $struct<T>
+        where
+            T: $crate::macros::floating::FloatingPoint
+            + num_traits::float::FloatCore
+        {
+            /// Linearly interpolates between two vectors by a normalized `weight` and normalizes the result.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(1.0, 0.0).nlerp(
+            ///     Vector2::new(-1.0, 2.0), 0.5
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(0.0, 1.0));
+            /// ```
+            #[inline]
+            pub fn nlerp(self, to: Self, weight: T) -> Self {
+                self.lerp(to, weight).normalized()
+            }
+
+            /// Spherically interpolates between two vectors by a normalized `weight`,
+            /// following the arc between their directions at a constant angular velocity.
+            /// Opposite vectors follow the arc through the axis least aligned with this vector.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(1.0, 0.0).slerp(
+            ///     Vector2::new(0.0, 1.0), 0.5
+            /// );
+            /// 
+            /// assert!((vec2.x - 0.5_f64.sqrt()).abs() < 1e-9);
+            /// assert!((vec2.y - 0.5_f64.sqrt()).abs() < 1e-9);
+            /// ```
+            pub fn slerp(self, to: Self, weight: T) -> Self {
+                let cos_angle = self.normalized().dot(&to.normalized())
+                    .max(-T::one())
+                    .min(T::one());
+
+                let angle = cos_angle.acos();
+                let sin_angle = angle.sin();
+
+                // NOTE: Nearly parallel vectors are indistinguishable from a linear interpolation,
+                // and would otherwise divide by a vanishing `sin_angle`.
+                if sin_angle.abs() <= T::epsilon() {
+                    let (from_length, to_length) = (self.length(), to.length());
+
+                    if cos_angle > T::zero() || from_length == T::zero() || to_length == T::zero() {
+                        return self.lerp(to, weight);
+                    }
+
+                    // NOTE: Opposite vectors have no single arc between them, so the arc passing through
+                    // the axis least aligned with `self` is followed instead of crossing the origin.
+                    let direction = self.normalized();
+                    let zero = Self { $( $field: T::zero() ), + };
+
+                    let mut axis = zero;
+                    let mut smallest = T::infinity();
+
+                    $(
+                        if direction.$field.abs() < smallest {
+                            smallest = direction.$field.abs();
+                            axis = Self { $field: T::one(), ..zero };
+                        }
+                    ) +
+
+                    let perpendicular = (axis - (direction * direction.dot(&axis))).normalized();
+                    let length = from_length + ((to_length - from_length) * weight);
+
+                    return ((direction * (weight * angle).cos()) + (perpendicular * (weight * angle).sin())) * length;
+                }
+
+                let from_scale = ((T::one() - weight) * angle).sin() / sin_angle;
+                let to_scale = (weight * angle).sin() / sin_angle;
+
+                Self {
+                    $( $field: (self.$field * from_scale) + (to.$field * to_scale) ), +
+                }
+            }
+        }
+    };
+}
@@ -1,4 +1,7 @@
 pub mod floating;
+mod bytes;
+mod constants;
+mod interpolation;
 
 
 #[doc(hidden)]
@@ -102,6 +105,108 @@ macro_rules! impl_vector {
             }
         }
 
+        impl<T: PartialOrd + Copy> $struct<T> {
+            /// Returns a vector containing the minimum of each field within the two vectors.
+            /// Unlike [`Ord::min`], which compares whole vectors lexicographically, each field is compared separately.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(1, 4).min_by_component(Vector2::new(3, 2));
+            /// 
+            /// assert_eq!(vec2, Vector2::new(1, 2));
+            /// ```
+            #[inline]
+            pub fn min_by_component(self, other: Self) -> Self {
+                Self {
+                    $( $field: if other.$field < self.$field { other.$field } else { self.$field } ), +
+                }
+            }
+
+            /// Returns a vector containing the maximum of each field within the two vectors.
+            /// Unlike [`Ord::max`], which compares whole vectors lexicographically, each field is compared separately.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(1, 4).max_by_component(Vector2::new(3, 2));
+            /// 
+            /// assert_eq!(vec2, Vector2::new(3, 4));
+            /// ```
+            #[inline]
+            pub fn max_by_component(self, other: Self) -> Self {
+                Self {
+                    $( $field: if other.$field > self.$field { other.$field } else { self.$field } ), +
+                }
+            }
+
+            /// Restricts each field within the vector between the matching fields within `min` and `max`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(-1, 8).clamp_by_component(
+            ///     Vector2::new(0, 0), Vector2::new(5, 5)
+            /// );
+            /// 
+            /// assert_eq!(vec2, Vector2::new(0, 5));
+            /// ```
+            #[inline]
+            pub fn clamp_by_component(self, min: Self, max: Self) -> Self {
+                self.max_by_component(min).min_by_component(max)
+            }
+        }
+
+        impl<T: $crate::LengthOrd> $struct<T> {
+            /// Compares the magnitudes of two vectors, returning [`None`] if they can't be compared,
+            /// such as when a field is `NaN`. The fields are squared within a wider type, so the
+            /// comparison can't overflow.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use core::cmp::Ordering;
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let ordering = Vector2::new(3, 4).cmp_by_length(&Vector2::new(-4, 1));
+            /// 
+            /// assert_eq!(ordering, Some(Ordering::Greater));
+            /// ```
+            #[inline]
+            pub fn cmp_by_length(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                let length_squared = T::wide_length_squared(&[ $( self.$field ), + ])?;
+                let other_length_squared = T::wide_length_squared(&[ $( other.$field ), + ])?;
+
+                Some(length_squared.cmp(&other_length_squared))
+            }
+
+            /// Sorts `vectors` from shortest to longest, moving vectors that can't be compared,
+            /// such as those containing `NaN`, to the end. The sort is unstable, and doesn't allocate.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let mut vectors = [Vector2::new(3, 4), Vector2::new(0, -1), Vector2::new(2, 0)];
+            /// Vector2::sort_by_length(&mut vectors);
+            /// 
+            /// assert_eq!(vectors, [Vector2::new(0, -1), Vector2::new(2, 0), Vector2::new(3, 4)]);
+            /// ```
+            pub fn sort_by_length(vectors: &mut [Self]) {
+                vectors.sort_unstable_by_key(|vector| {
+                    let length_squared = T::wide_length_squared(&[ $( vector.$field ), + ]);
+                    (length_squared.is_none(), length_squared)
+                });
+            }
+        }
+
         impl<T> From<[T; $size]> for $struct<T> {
             fn from(from: [T; $size]) -> Self {
                 let mut iterator = from.into_iter();
@@ -133,6 +238,17 @@ macro_rules! impl_vector {
             }
         }
 
+        $crate::impl_format!( $struct { $($field), + }, Display, LowerHex, UpperHex, Binary, Octal, LowerExp, UpperExp );
+
+        impl<T: core::str::FromStr> core::str::FromStr for $struct<T> {
+            type Err = $crate::ParseVectorError<T::Err>;
+
+            fn from_str(string: &str) -> Result<Self, Self::Err> {
+                let fields = [ $( core::stringify!($field) ), + ];
+                $crate::parse::parse_components(string, core::stringify!($struct), fields).map(Self::from)
+            }
+        }
+
         impl<T: PartialEq> PartialEq for $struct<T> {
             fn eq(&self, other: &Self) -> bool {
                 $( self.$field == other.$field ) && +
@@ -141,6 +257,32 @@ macro_rules! impl_vector {
 
         impl<T: Eq> Eq for $struct<T> {  }
 
+        impl<T: PartialOrd> PartialOrd for $struct<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                $(
+                    match self.$field.partial_cmp(&other.$field) {
+                        Some(core::cmp::Ordering::Equal) => {  },
+                        ordering => return ordering,
+                    }
+                ) +
+
+                Some(core::cmp::Ordering::Equal)
+            }
+        }
+
+        impl<T: Ord> Ord for $struct<T> {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                $(
+                    match self.$field.cmp(&other.$field) {
+                        core::cmp::Ordering::Equal => {  },
+                        ordering => return ordering,
+                    }
+                ) +
+
+                core::cmp::Ordering::Equal
+            }
+        }
+
         impl<T: core::hash::Hash> core::hash::Hash for $struct<T> {
             fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
                 $( self.$field.hash(state); ) +
@@ -197,6 +339,9 @@ macro_rules! impl_vector {
 
         // Impl floating-point based methods
         $crate::impl_floating_point_operations!( $struct { $($field), + }, $size );
+
+        // Impl interpolation methods
+        $crate::impl_interpolation!( $struct { $($field), + } );
     };
 }
 
@@ -239,4 +384,145 @@ macro_rules! impl_operator {
             }
         }
     };
+}
+
+
+// Implements formatting traits by writing the fields as a tuple, forwarding the formatter's options to each field.
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_format {
+    ( $struct: ident $fields: tt, $($trait: ident), + ) => {
+        $( $crate::impl_format!( @trait $struct $fields, $trait ); ) +
+    };
+
+    ( @trait $struct: ident { $($field: ident), + }, $trait: ident ) => {
+        impl<T: core::fmt::$trait> core::fmt::$trait for $struct<T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("(")?;
+
+                for (index, value) in [ $( &self.$field ), + ].into_iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    core::fmt::$trait::fmt(value, f)?;
+                }
+
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_aligned_conversions {
+    ( $aligned: ident, $struct: ident { $($field: ident), + } ) => {
+        impl<T> From<$struct<T>> for $aligned<T> {
+            #[inline(always)]
+            fn from(from: $struct<T>) -> Self {
+                Self {
+                    $( $field: from.$field ), +
+                }
+            }
+        }
+
+        impl<T> From<$aligned<T>> for $struct<T> {
+            #[inline(always)]
+            fn from(from: $aligned<T>) -> Self {
+                Self {
+                    $( $field: from.$field ), +
+                }
+            }
+        }
+    };
+}
+
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_cross {
+    ( $struct: ident ) => {
+        impl<T> $struct<T>
+        where
+            T: core::ops::Mul<Output = T>
+            + core::ops::Sub<Output = T>
+            + Copy
+        {
+            /// Returns the cross product of two vectors, a vector perpendicular to both of them.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector3;
+            /// 
+            /// let a = Vector3::new(1, 0, 0);
+            /// let b = Vector3::new(0, 1, 0);
+            /// 
+            /// assert_eq!(a.cross(&b), Vector3::new(0, 0, 1));
+            /// ```
+            #[inline]
+            pub fn cross(&self, other: &Self) -> Self {
+                Self {
+                    x: (self.y * other.z) - (self.z * other.y),
+                    y: (self.z * other.x) - (self.x * other.z),
+                    z: (self.x * other.y) - (self.y * other.x),
+                }
+            }
+        }
+    };
+}
+
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! impl_perp {
+    ( $struct: ident ) => {
+        impl<T: core::ops::Neg<Output = T> + Copy> $struct<T> {
+            /// Returns the vector rotated by 90 degrees counter-clockwise.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let vec2 = Vector2::new(1, 2).perp();
+            /// 
+            /// assert_eq!(vec2, Vector2::new(-2, 1));
+            /// ```
+            #[inline]
+            pub fn perp(self) -> Self {
+                Self {
+                    x: -self.y,
+                    y: self.x,
+                }
+            }
+        }
+
+        impl<T> $struct<T>
+        where
+            T: core::ops::Mul<Output = T>
+            + core::ops::Sub<Output = T>
+            + Copy
+        {
+            /// Returns the perpendicular dot product of two vectors, also known as the two-dimensional cross product,
+            /// which is positive when `other` lies counter-clockwise of this vector.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let a = Vector2::new(1, 0);
+            /// let b = Vector2::new(0, 1);
+            /// 
+            /// assert_eq!(a.perp_dot(&b), 1);
+            /// ```
+            #[inline]
+            pub fn perp_dot(&self, other: &Self) -> T {
+                (self.x * other.y) - (self.y * other.x)
+            }
+        }
+    };
 }
\ No newline at end of file
@@ -0,0 +1,597 @@
+use crate::{Affine3, Vector3, Vector4};
+use crate::macros::floating::FloatingPoint;
+use num_traits::float::FloatCore;
+
+
+/// Four-by-four matrix stored as the columns `x_axis`, `y_axis`, `z_axis` and `w_axis`,
+/// used for projective transformations such as camera projection and view matrices.
+/// 
+/// Projections follow the column-vector convention, transforming a vector `v` as `matrix * v`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Matrix4, Vector3};
+/// 
+/// let projection = Matrix4::perspective_rh(core::f32::consts::FRAC_PI_2, 1.0, 1.0, 100.0);
+/// let projected = projection.project_point(Vector3::new(0.0, 0.0, -1.0));
+/// 
+/// assert_eq!(projected, Vector3::new(0.0, 0.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix4<T> {
+    pub x_axis: Vector4<T>,
+    pub y_axis: Vector4<T>,
+    pub z_axis: Vector4<T>,
+    pub w_axis: Vector4<T>,
+}
+
+
+impl<T> Matrix4<T> {
+    /// Constructs a new matrix from its four columns.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector4};
+    /// 
+    /// let matrix = Matrix4::new(
+    ///     Vector4::new(1, 0, 0, 0), Vector4::new(0, 1, 0, 0),
+    ///     Vector4::new(0, 0, 1, 0), Vector4::new(4, 5, 6, 1),
+    /// );
+    /// 
+    /// assert_eq!(matrix.w_axis, Vector4::new(4, 5, 6, 1));
+    /// ```
+    #[inline(always)]
+    pub const fn new(x_axis: Vector4<T>, y_axis: Vector4<T>, z_axis: Vector4<T>, w_axis: Vector4<T>) -> Self {
+        Self { x_axis, y_axis, z_axis, w_axis }
+    }
+}
+
+
+impl<T: Copy> Matrix4<T> {
+    /// Returns the matrix with its rows and columns swapped.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector4};
+    /// 
+    /// let matrix = Matrix4::new(
+    ///     Vector4::new(1, 0, 0, 0), Vector4::new(0, 1, 0, 0),
+    ///     Vector4::new(0, 0, 1, 0), Vector4::new(4, 5, 6, 1),
+    /// ).transpose();
+    /// 
+    /// assert_eq!(matrix.x_axis, Vector4::new(1, 0, 0, 4));
+    /// assert_eq!(matrix.w_axis, Vector4::new(0, 0, 0, 1));
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let (x, y, z, w) = (self.x_axis, self.y_axis, self.z_axis, self.w_axis);
+
+        Self::new(
+            Vector4::new(x.x, y.x, z.x, w.x),
+            Vector4::new(x.y, y.y, z.y, w.y),
+            Vector4::new(x.z, y.z, z.z, w.z),
+            Vector4::new(x.w, y.w, z.w, w.w),
+        )
+    }
+
+    /// Consumes the matrix and returns its values as an array of columns.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector4};
+    /// 
+    /// let matrix = Matrix4::new(
+    ///     Vector4::new(1, 0, 0, 0), Vector4::new(0, 1, 0, 0),
+    ///     Vector4::new(0, 0, 1, 0), Vector4::new(4, 5, 6, 1),
+    /// );
+    /// 
+    /// assert_eq!(matrix.to_cols_array()[3], [4, 5, 6, 1]);
+    /// ```
+    #[inline]
+    pub fn to_cols_array(self) -> [[T; 4]; 4] {
+        [self.x_axis.to_array(), self.y_axis.to_array(), self.z_axis.to_array(), self.w_axis.to_array()]
+    }
+}
+
+
+impl<T: FloatCore> Matrix4<T> {
+    /// Returns the matrix which leaves every vector unchanged.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector4};
+    /// 
+    /// let matrix = Matrix4::<f32>::identity();
+    /// 
+    /// assert_eq!(matrix.transform(Vector4::new(1.0, 2.0, 3.0, 1.0)), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        let (zero, one) = (T::zero(), T::one());
+
+        Self::new(
+            Vector4::new(one, zero, zero, zero),
+            Vector4::new(zero, one, zero, zero),
+            Vector4::new(zero, zero, one, zero),
+            Vector4::new(zero, zero, zero, one),
+        )
+    }
+
+    /// Transforms `vector` by the matrix.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector4};
+    /// 
+    /// let matrix = Matrix4::<f32>::identity() * 2.0;
+    /// 
+    /// assert_eq!(matrix.transform(Vector4::new(1.0, 2.0, 3.0, 1.0)), Vector4::new(2.0, 4.0, 6.0, 2.0));
+    /// ```
+    #[inline]
+    pub fn transform(&self, vector: Vector4<T>) -> Vector4<T> {
+        (self.x_axis * vector.x) + (self.y_axis * vector.y) + (self.z_axis * vector.z) + (self.w_axis * vector.w)
+    }
+
+    /// Transforms `point` by the matrix and performs the perspective divide,
+    /// mapping a point in view space into normalized device coordinates for projection matrices.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::orthographic_rh(-2.0, 2.0, -2.0, 2.0, 1.0, 3.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(1.0, -2.0, -3.0)), Vector3::new(0.5, -1.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn project_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let clip = self.transform(Vector4::new(point.x, point.y, point.z, T::one()));
+        Vector3::new(clip.x, clip.y, clip.z) / clip.w
+    }
+
+    /// Maps `point` from normalized device coordinates back into the space the matrix projects from,
+    /// or returns [`None`] if the matrix cannot be inverted.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::orthographic_rh(-2.0, 2.0, -2.0, 2.0, 1.0, 3.0);
+    /// 
+    /// assert_eq!(projection.unproject_point(Vector3::new(0.5, -1.0, 1.0)), Some(Vector3::new(1.0, -2.0, -3.0)));
+    /// ```
+    #[inline]
+    pub fn unproject_point(&self, point: Vector3<T>) -> Option<Vector3<T>> {
+        Some(self.inverse()?.project_point(point))
+    }
+
+    /// Projects `point` into screen space within `viewport`, given as `(x, y, width, height)`.
+    /// 
+    /// Normalized device coordinates from `-1.0` to `1.0` on the `x` and `y` axes are mapped onto the
+    /// viewport with `y` pointing upwards, while the depth is kept in normalized device coordinates.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3, Vector4};
+    /// 
+    /// let projection = Matrix4::orthographic_rh(-2.0, 2.0, -2.0, 2.0, 1.0, 3.0);
+    /// let viewport = Vector4::new(0.0, 0.0, 800.0, 600.0);
+    /// 
+    /// assert_eq!(projection.project(Vector3::new(1.0, -2.0, -3.0), viewport), Vector3::new(600.0, 0.0, 1.0));
+    /// ```
+    pub fn project(&self, point: Vector3<T>, viewport: Vector4<T>) -> Vector3<T> {
+        let half = T::one() / (T::one() + T::one());
+        let ndc = self.project_point(point);
+
+        Vector3::new(
+            viewport.x + ((ndc.x + T::one()) * half * viewport.z),
+            viewport.y + ((ndc.y + T::one()) * half * viewport.w),
+            ndc.z,
+        )
+    }
+
+    /// Maps `point` from screen space within `viewport` back into the space the matrix projects from,
+    /// reversing [`project`](Self::project), or returns [`None`] if the matrix cannot be inverted.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3, Vector4};
+    /// 
+    /// let projection = Matrix4::orthographic_rh(-2.0, 2.0, -2.0, 2.0, 1.0, 3.0);
+    /// let viewport = Vector4::new(0.0, 0.0, 800.0, 600.0);
+    /// 
+    /// assert_eq!(projection.unproject(Vector3::new(600.0, 0.0, 1.0), viewport), Some(Vector3::new(1.0, -2.0, -3.0)));
+    /// ```
+    pub fn unproject(&self, point: Vector3<T>, viewport: Vector4<T>) -> Option<Vector3<T>> {
+        let two = T::one() + T::one();
+
+        let ndc = Vector3::new(
+            (((point.x - viewport.x) / viewport.z) * two) - T::one(),
+            (((point.y - viewport.y) / viewport.w) * two) - T::one(),
+            point.z,
+        );
+
+        self.unproject_point(ndc)
+    }
+
+    /// Returns the determinant of the matrix, which is zero when it cannot be inverted.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::Matrix4;
+    /// 
+    /// let matrix = Matrix4::<f32>::identity() * 2.0;
+    /// 
+    /// assert_eq!(matrix.determinant(), 16.0);
+    /// assert_eq!((matrix * 0.0).determinant(), 0.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        Self::determinant_from_minors(&self.minors())
+    }
+
+    // Returns the twelve two-by-two minors shared by the determinant and the inverse,
+    // taken from the top and bottom halves of the columns.
+    fn minors(&self) -> [T; 12] {
+        let [[a00, a01, a02, a03], [a10, a11, a12, a13], [a20, a21, a22, a23], [a30, a31, a32, a33]] = self.to_cols_array();
+
+        [
+            (a00 * a11) - (a01 * a10),
+            (a00 * a12) - (a02 * a10),
+            (a00 * a13) - (a03 * a10),
+            (a01 * a12) - (a02 * a11),
+            (a01 * a13) - (a03 * a11),
+            (a02 * a13) - (a03 * a12),
+            (a20 * a31) - (a21 * a30),
+            (a20 * a32) - (a22 * a30),
+            (a20 * a33) - (a23 * a30),
+            (a21 * a32) - (a22 * a31),
+            (a21 * a33) - (a23 * a31),
+            (a22 * a33) - (a23 * a32),
+        ]
+    }
+
+    #[inline]
+    fn determinant_from_minors(minors: &[T; 12]) -> T {
+        let [b00, b01, b02, b03, b04, b05, b06, b07, b08, b09, b10, b11] = *minors;
+        (b00 * b11) - (b01 * b10) + (b02 * b09) + (b03 * b08) - (b04 * b07) + (b05 * b06)
+    }
+
+    /// Returns the inverse of the matrix, or [`None`] if its determinant is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::Matrix4;
+    /// 
+    /// let matrix = Matrix4::<f32>::identity() * 2.0;
+    /// 
+    /// assert_eq!(matrix.inverse(), Some(Matrix4::identity() * 0.5));
+    /// assert_eq!((matrix * 0.0).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let [[a00, a01, a02, a03], [a10, a11, a12, a13], [a20, a21, a22, a23], [a30, a31, a32, a33]] = self.to_cols_array();
+
+        let minors = self.minors();
+        let [b00, b01, b02, b03, b04, b05, b06, b07, b08, b09, b10, b11] = minors;
+        let determinant = Self::determinant_from_minors(&minors);
+
+        if determinant == T::zero() {
+            return None;
+        }
+
+        let inverse = Self::new(
+            Vector4::new(
+                (a11 * b11) - (a12 * b10) + (a13 * b09),
+                (a02 * b10) - (a01 * b11) - (a03 * b09),
+                (a31 * b05) - (a32 * b04) + (a33 * b03),
+                (a22 * b04) - (a21 * b05) - (a23 * b03),
+            ),
+            Vector4::new(
+                (a12 * b08) - (a10 * b11) - (a13 * b07),
+                (a00 * b11) - (a02 * b08) + (a03 * b07),
+                (a32 * b02) - (a30 * b05) - (a33 * b01),
+                (a20 * b05) - (a22 * b02) + (a23 * b01),
+            ),
+            Vector4::new(
+                (a10 * b10) - (a11 * b08) + (a13 * b06),
+                (a01 * b08) - (a00 * b10) - (a03 * b06),
+                (a30 * b04) - (a31 * b02) + (a33 * b00),
+                (a21 * b02) - (a20 * b04) - (a23 * b00),
+            ),
+            Vector4::new(
+                (a11 * b07) - (a10 * b09) - (a12 * b06),
+                (a00 * b09) - (a01 * b07) + (a02 * b06),
+                (a31 * b01) - (a30 * b03) - (a32 * b00),
+                (a20 * b03) - (a21 * b01) + (a22 * b00),
+            ),
+        );
+
+        Some(inverse * (T::one() / determinant))
+    }
+
+    /// Constructs a right-handed orthographic projection mapping depth from `near` to `far` onto `0.0..1.0`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::orthographic_rh(-2.0, 2.0, -2.0, 2.0, 1.0, 3.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(2.0, 2.0, -1.0)), Vector3::new(1.0, 1.0, 0.0));
+    /// assert_eq!(projection.project_point(Vector3::new(-2.0, -2.0, -3.0)), Vector3::new(-1.0, -1.0, 1.0));
+    /// ```
+    pub fn orthographic_rh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic(left, right, bottom, top, T::one() / (near - far), near / (near - far))
+    }
+
+    /// Constructs a left-handed orthographic projection mapping depth from `near` to `far` onto `0.0..1.0`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::orthographic_lh(-2.0, 2.0, -2.0, 2.0, 1.0, 3.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(2.0, 2.0, 1.0)), Vector3::new(1.0, 1.0, 0.0));
+    /// assert_eq!(projection.project_point(Vector3::new(-2.0, -2.0, 3.0)), Vector3::new(-1.0, -1.0, 1.0));
+    /// ```
+    pub fn orthographic_lh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic(left, right, bottom, top, T::one() / (far - near), near / (near - far))
+    }
+
+    /// Constructs a right-handed orthographic projection mapping depth from `near` to `far` onto `-1.0..1.0`,
+    /// following the OpenGL convention.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::orthographic_rh_gl(-2.0, 2.0, -2.0, 2.0, 1.0, 3.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(2.0, 2.0, -1.0)), Vector3::new(1.0, 1.0, -1.0));
+    /// assert_eq!(projection.project_point(Vector3::new(-2.0, -2.0, -3.0)), Vector3::new(-1.0, -1.0, 1.0));
+    /// ```
+    pub fn orthographic_rh_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        Self::orthographic(left, right, bottom, top, two / (near - far), (far + near) / (near - far))
+    }
+
+    /// Constructs a left-handed orthographic projection mapping depth from `near` to `far` onto `-1.0..1.0`,
+    /// following the OpenGL convention.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::orthographic_lh_gl(-2.0, 2.0, -2.0, 2.0, 1.0, 3.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(2.0, 2.0, 1.0)), Vector3::new(1.0, 1.0, -1.0));
+    /// assert_eq!(projection.project_point(Vector3::new(-2.0, -2.0, 3.0)), Vector3::new(-1.0, -1.0, 1.0));
+    /// ```
+    pub fn orthographic_lh_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        Self::orthographic(left, right, bottom, top, two / (far - near), (far + near) / (near - far))
+    }
+
+    fn orthographic(left: T, right: T, bottom: T, top: T, depth_scale: T, depth_offset: T) -> Self {
+        let two = T::one() + T::one();
+        let (zero, one) = (T::zero(), T::one());
+
+        let width = right - left;
+        let height = top - bottom;
+
+        Self::new(
+            Vector4::new(two / width, zero, zero, zero),
+            Vector4::new(zero, two / height, zero, zero),
+            Vector4::new(zero, zero, depth_scale, zero),
+            Vector4::new(-(right + left) / width, -(top + bottom) / height, depth_offset, one),
+        )
+    }
+}
+
+
+impl<T: FloatingPoint + FloatCore> Matrix4<T> {
+    /// Constructs a right-handed perspective projection with a vertical field of view of `fov_y` radians,
+    /// mapping depth from `near` to `far` onto `0.0..1.0`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::perspective_rh(core::f32::consts::FRAC_PI_2, 1.0, 1.0, 2.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(0.0, 0.0, -1.0)).z, 0.0);
+    /// assert_eq!(projection.project_point(Vector3::new(0.0, 0.0, -2.0)).z, 1.0);
+    /// ```
+    pub fn perspective_rh(fov_y: T, aspect_ratio: T, near: T, far: T) -> Self {
+        let depth_scale = far / (near - far);
+        Self::perspective(fov_y, aspect_ratio, depth_scale, depth_scale * near, -T::one())
+    }
+
+    /// Constructs a left-handed perspective projection with a vertical field of view of `fov_y` radians,
+    /// mapping depth from `near` to `far` onto `0.0..1.0`.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::perspective_lh(core::f32::consts::FRAC_PI_2, 1.0, 1.0, 2.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(0.0, 0.0, 1.0)).z, 0.0);
+    /// assert_eq!(projection.project_point(Vector3::new(0.0, 0.0, 2.0)).z, 1.0);
+    /// ```
+    pub fn perspective_lh(fov_y: T, aspect_ratio: T, near: T, far: T) -> Self {
+        let depth_scale = far / (far - near);
+        Self::perspective(fov_y, aspect_ratio, depth_scale, -depth_scale * near, T::one())
+    }
+
+    /// Constructs a right-handed perspective projection with a vertical field of view of `fov_y` radians,
+    /// mapping depth from `near` to `far` onto `-1.0..1.0`, following the OpenGL convention.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::perspective_rh_gl(core::f32::consts::FRAC_PI_2, 1.0, 1.0, 2.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(0.0, 0.0, -1.0)).z, -1.0);
+    /// assert_eq!(projection.project_point(Vector3::new(0.0, 0.0, -2.0)).z, 1.0);
+    /// ```
+    pub fn perspective_rh_gl(fov_y: T, aspect_ratio: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let depth_scale = (far + near) / (near - far);
+        Self::perspective(fov_y, aspect_ratio, depth_scale, two * far * near / (near - far), -T::one())
+    }
+
+    /// Constructs a left-handed perspective projection with a vertical field of view of `fov_y` radians,
+    /// mapping depth from `near` to `far` onto `-1.0..1.0`, following the OpenGL convention.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let projection = Matrix4::perspective_lh_gl(core::f32::consts::FRAC_PI_2, 1.0, 1.0, 2.0);
+    /// 
+    /// assert_eq!(projection.project_point(Vector3::new(0.0, 0.0, 1.0)).z, -1.0);
+    /// assert_eq!(projection.project_point(Vector3::new(0.0, 0.0, 2.0)).z, 1.0);
+    /// ```
+    pub fn perspective_lh_gl(fov_y: T, aspect_ratio: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let depth_scale = (far + near) / (far - near);
+        Self::perspective(fov_y, aspect_ratio, depth_scale, two * far * near / (near - far), T::one())
+    }
+
+    fn perspective(fov_y: T, aspect_ratio: T, depth_scale: T, depth_offset: T, handedness: T) -> Self {
+        let half_fov = fov_y / (T::one() + T::one());
+        let height = half_fov.cos() / half_fov.sin();
+        let width = height / aspect_ratio;
+        let zero = T::zero();
+
+        Self::new(
+            Vector4::new(width, zero, zero, zero),
+            Vector4::new(zero, height, zero, zero),
+            Vector4::new(zero, zero, depth_scale, handedness),
+            Vector4::new(zero, zero, depth_offset, zero),
+        )
+    }
+
+    /// Constructs a right-handed view matrix for a camera at `eye` looking towards `target`,
+    /// with the camera looking down its negative `z` axis.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let view = Matrix4::look_at_rh(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// 
+    /// assert_eq!(view.project_point(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -5.0));
+    /// ```
+    pub fn look_at_rh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        Self::look_to(eye, -(target - eye).normalized(), up)
+    }
+
+    /// Constructs a left-handed view matrix for a camera at `eye` looking towards `target`,
+    /// with the camera looking down its positive `z` axis.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use fixed_vectors::{Matrix4, Vector3};
+    /// 
+    /// let view = Matrix4::look_at_lh(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// 
+    /// assert_eq!(view.project_point(Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, 5.0));
+    /// ```
+    pub fn look_at_lh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        Self::look_to(eye, (target - eye).normalized(), up)
+    }
+
+    fn look_to(eye: Vector3<T>, backward: Vector3<T>, up: Vector3<T>) -> Self {
+        let side = up.cross(&backward).normalized();
+        let up = backward.cross(&side);
+        let (zero, one) = (T::zero(), T::one());
+
+        Self::new(
+            Vector4::new(side.x, up.x, backward.x, zero),
+            Vector4::new(side.y, up.y, backward.y, zero),
+            Vector4::new(side.z, up.z, backward.z, zero),
+            Vector4::new(-side.dot(&eye), -up.dot(&eye), -backward.dot(&eye), one),
+        )
+    }
+}
+
+
+impl<T: FloatCore> Default for Matrix4<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+
+impl<T: FloatCore> From<Affine3<T>> for Matrix4<T> {
+    fn from(from: Affine3<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let (x, y, z, translation) = (from.x_axis, from.y_axis, from.z_axis, from.translation);
+
+        Self::new(
+            Vector4::new(x.x, x.y, x.z, zero),
+            Vector4::new(y.x, y.y, y.z, zero),
+            Vector4::new(z.x, z.y, z.z, zero),
+            Vector4::new(translation.x, translation.y, translation.z, one),
+        )
+    }
+}
+
+
+impl<T: FloatCore> core::ops::Mul for Matrix4<T> {
+    type Output = Self;
+
+    /// Multiplies the two matrices, producing one which applies `other` first and then `self`.
+    fn mul(self, other: Self) -> Self::Output {
+        Self::new(
+            self.transform(other.x_axis),
+            self.transform(other.y_axis),
+            self.transform(other.z_axis),
+            self.transform(other.w_axis),
+        )
+    }
+}
+
+
+impl<T: FloatCore> core::ops::Mul<T> for Matrix4<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        Self::new(self.x_axis * other, self.y_axis * other, self.z_axis * other, self.w_axis * other)
+    }
+}
+
+
+impl<T: FloatCore> core::ops::Mul<Vector4<T>> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    #[inline]
+    fn mul(self, other: Vector4<T>) -> Self::Output {
+        self.transform(other)
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{Vector2, Vector3};
+
+
+// Returns a mask selecting `chunk` bits out of every `chunk * stride` bits.
+const fn chunk_mask(chunk: u32, stride: u32) -> u128 {
+    let mut mask = 0;
+    let mut offset = 0;
+
+    while offset < u128::BITS {
+        mask |= (u128::MAX >> (u128::BITS - chunk)) << offset;
+        offset += chunk * stride;
+    }
+
+    mask
+}
+
+
+// Spreads the bits of `value` apart so that each is followed by `stride - 1` zeroed bits,
+// by repeatedly halving the size of the chunks being moved.
+#[inline]
+const fn spread_bits(mut value: u128, stride: u32) -> u128 {
+    let mut chunk = u128::BITS / 2;
+
+    while chunk > 0 {
+        let shift = (stride - 1) * chunk;
+
+        if shift < u128::BITS {
+            value = (value | (value << shift)) & chunk_mask(chunk, stride);
+        }
+
+        chunk /= 2;
+    }
+
+    value
+}
+
+
+// Reverses `spread_bits`, gathering every `stride`-th bit of `value` into the low bits.
+#[inline]
+const fn compact_bits(value: u128, stride: u32) -> u128 {
+    let mut value = value & chunk_mask(1, stride);
+    let mut chunk = 1;
+
+    while chunk < u128::BITS {
+        let shift = (stride - 1) * chunk;
+
+        if shift < u128::BITS {
+            value = (value | (value >> shift)) & chunk_mask(chunk * 2, stride);
+        }
+
+        chunk *= 2;
+    }
+
+    value
+}
+
+
+// Interleaves the bits of `axes`, with those of the first axis being least significant.
+#[inline]
+fn interleave<const N: usize>(axes: [u64; N]) -> u128 {
+    let mut code = 0;
+
+    for (axis, value) in axes.into_iter().enumerate() {
+        code |= spread_bits(value as u128, N as u32) << axis;
+    }
+
+    code
+}
+
+
+// Reverses `interleave`.
+#[inline]
+fn deinterleave<const N: usize>(code: u128) -> [u64; N] {
+    core::array::from_fn(|axis| compact_bits(code >> axis, N as u32) as u64)
+}
+
+
+// Converts `axes` into the transposed form of their Hilbert index, following John Skilling's
+// "Programming the Hilbert curve", where each axis holds every `N`-th bit of the index.
+fn axes_to_transpose<const N: usize>(mut axes: [u64; N], bits: u32) -> [u64; N] {
+    let high = 1 << (bits - 1);
+    let mut q = high;
+
+    // Inverse undo.
+    while q > 1 {
+        let p = q - 1;
+
+        for i in 0..N {
+            if axes[i] & q != 0 {
+                axes[0] ^= p;
+            } else {
+                let t = (axes[0] ^ axes[i]) & p;
+                axes[0] ^= t;
+                axes[i] ^= t;
+            }
+        }
+
+        q >>= 1;
+    }
+
+    // Gray encode.
+    for i in 1..N {
+        axes[i] ^= axes[i - 1];
+    }
+
+    let mut t = 0;
+    q = high;
+
+    while q > 1 {
+        if axes[N - 1] & q != 0 {
+            t ^= q - 1;
+        }
+
+        q >>= 1;
+    }
+
+    for axis in &mut axes {
+        *axis ^= t;
+    }
+
+    axes
+}
+
+
+// Reverses `axes_to_transpose`.
+fn transpose_to_axes<const N: usize>(mut axes: [u64; N], bits: u32) -> [u64; N] {
+    // Gray decode.
+    let t = axes[N - 1] >> 1;
+
+    for i in (1..N).rev() {
+        axes[i] ^= axes[i - 1];
+    }
+
+    axes[0] ^= t;
+
+    // Undo excess work.
+    for bit in 1..bits {
+        let q = 1 << bit;
+        let p = q - 1;
+
+        for i in (0..N).rev() {
+            if axes[i] & q != 0 {
+                axes[0] ^= p;
+            } else {
+                let t = (axes[0] ^ axes[i]) & p;
+                axes[0] ^= t;
+                axes[i] ^= t;
+            }
+        }
+    }
+
+    axes
+}
+
+
+// Returns the Hilbert index of `axes` on the curve filling a cube of `2^bits` cells per side.
+#[inline]
+fn hilbert_index<const N: usize>(axes: [u64; N], bits: u32) -> u128 {
+    let mut transpose = axes_to_transpose(axes, bits);
+
+    // NOTE: The first axis holds the most significant bit of each group within the index.
+    transpose.reverse();
+    interleave(transpose)
+}
+
+
+// Reverses `hilbert_index`.
+#[inline]
+fn hilbert_axes<const N: usize>(index: u128, bits: u32) -> [u64; N] {
+    let mut transpose = deinterleave(index);
+
+    transpose.reverse();
+    transpose_to_axes(transpose, bits)
+}
+
+
+// Maps the low `bits` of `value` onto an unsigned range in the same order,
+// by flipping their highest bit when `value` is signed.
+#[inline(always)]
+const fn to_unsigned(value: u64, bits: u32, signed: bool) -> u64 {
+    let value = value & (u64::MAX >> (u64::BITS - bits));
+
+    if signed { value ^ (1 << (bits - 1)) } else { value }
+}
+
+
+// Reverses `to_unsigned`, sign extending signed values to 64 bits.
+#[inline(always)]
+const fn from_unsigned(value: u64, bits: u32, signed: bool) -> u64 {
+    if signed {
+        let shift = u64::BITS - bits;
+        (((value ^ (1 << (bits - 1))) << shift) as i64 >> shift) as u64
+    } else {
+        value
+    }
+}
+
+
+macro_rules! impl_morton {
+    ( $struct: ident $fields: tt, $stride: expr, $($type: ty => $code: ty: $bits: expr), + ) => {
+        $( impl_morton!( @type $struct $fields, $stride, $type, $code, $bits ); ) +
+    };
+
+    ( @type $struct: ident { $($field: ident), + }, $stride: expr, $type: ty, $code: ty, $bits: expr ) => {
+        impl $struct<$type> {
+            #[doc = core::concat!(
+                "Returns the Morton code of the vector, interleaving the low ", core::stringify!($bits),
+                " bits of each field with those of `x` being least significant. Higher bits are discarded, and signed ",
+                "fields are offset so that negative values are ordered before positive ones."
+            )]
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// assert_eq!(Vector2::new(0b11_u16, 0b00).to_morton(), 0b0101);
+            /// assert_eq!(Vector2::<u16>::from_morton(0b1010), Vector2::new(0b00, 0b11));
+            /// assert!(Vector2::new(-1_i16, 0).to_morton() < Vector2::new(0_i16, 0).to_morton());
+            /// ```
+            #[inline]
+            pub fn to_morton(&self) -> $code {
+                interleave([ $( to_unsigned(self.$field as u64, $bits, <$type>::MIN != 0) ), + ]) as $code
+            }
+
+            /// Constructs a vector from its Morton code, reversing [`to_morton`](Self::to_morton).
+            /// Bits of `code` beyond those used by `to_morton` are ignored.
+            #[inline]
+            pub fn from_morton(code: $code) -> Self {
+                let mask = u128::MAX >> (u128::BITS - $stride * $bits);
+                let [ $( $field ), + ] = deinterleave::<$stride>(code as u128 & mask);
+
+                Self {
+                    $( $field: from_unsigned($field, $bits, <$type>::MIN != 0) as $type ), +
+                }
+            }
+
+            #[doc = core::concat!(
+                "Returns the index of the vector along the Hilbert curve filling a cube of `2^", core::stringify!($bits),
+                "` cells per side, using the low ", core::stringify!($bits), " bits of each field, offset like those of ",
+                "[`to_morton`](Self::to_morton). Vectors with consecutive indices are always adjacent, which keeps ",
+                "nearby vectors closer together than the Z-order curve."
+            )]
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let index = Vector2::new(3_u8, 5).to_hilbert();
+            /// 
+            /// let next = Vector2::<u8>::from_hilbert(index + 1);
+            /// 
+            /// assert_eq!(Vector2::<u8>::from_hilbert(index), Vector2::new(3, 5));
+            /// assert_eq!(next.x.abs_diff(3) + next.y.abs_diff(5), 1);
+            /// ```
+            #[inline]
+            pub fn to_hilbert(&self) -> $code {
+                hilbert_index([ $( to_unsigned(self.$field as u64, $bits, <$type>::MIN != 0) ), + ], $bits) as $code
+            }
+
+            /// Constructs a vector from its index along the Hilbert curve, reversing [`to_hilbert`](Self::to_hilbert).
+            /// Bits of `index` beyond those used by `to_hilbert` are ignored.
+            #[inline]
+            pub fn from_hilbert(index: $code) -> Self {
+                let mask = u128::MAX >> (u128::BITS - $stride * $bits);
+                let [ $( $field ), + ] = hilbert_axes::<$stride>(index as u128 & mask, $bits);
+
+                Self {
+                    $( $field: from_unsigned($field, $bits, <$type>::MIN != 0) as $type ), +
+                }
+            }
+
+            /// Sorts `vectors` along the Z-order curve, which keeps vectors that are close together in space
+            /// close together within the slice. Unlike [`to_morton`](Self::to_morton), every bit of each
+            /// field is used. The sort is unstable, and doesn't allocate.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2;
+            /// 
+            /// let mut cells = [Vector2::new(1_i32, 1), Vector2::new(-1, -1), Vector2::new(1, 0), Vector2::new(0, 0)];
+            /// Vector2::<i32>::sort_by_z_order(&mut cells);
+            /// 
+            /// assert_eq!(cells, [Vector2::new(-1, -1), Vector2::new(0, 0), Vector2::new(1, 0), Vector2::new(1, 1)]);
+            /// ```
+            #[inline]
+            pub fn sort_by_z_order(vectors: &mut [Self]) {
+                vectors.sort_unstable_by_key(|vector| {
+                    let $struct { $( $field ), + } = *vector;
+                    interleave([ $( to_unsigned($field as u64, <$type>::BITS, <$type>::MIN != 0) ), + ])
+                });
+            }
+        }
+    };
+}
+
+
+impl_morton!(Vector2 { x, y }, 2, u8 => u16: 8, u16 => u32: 16, u32 => u64: 32, u64 => u128: 64);
+impl_morton!(Vector2 { x, y }, 2, i8 => u16: 8, i16 => u32: 16, i32 => u64: 32, i64 => u128: 64);
+impl_morton!(Vector3 { x, y, z }, 3, u8 => u32: 8, u16 => u64: 16, u32 => u64: 21);
+impl_morton!(Vector3 { x, y, z }, 3, i8 => u32: 8, i16 => u64: 16, i32 => u64: 21);
\ No newline at end of file
@@ -0,0 +1,115 @@
+use core::fmt;
+use core::str::FromStr;
+
+
+/// Error returned when parsing a vector from a string fails.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{ParseVectorError, Vector2};
+/// 
+/// assert_eq!("(1, 2)".parse(), Ok(Vector2::new(1, 2)));
+/// assert_eq!("(1, 2, 3)".parse::<Vector2<i32>>(), Err(ParseVectorError::ComponentCount { expected: 2, found: 3 }));
+/// assert!(matches!("(1, a)".parse::<Vector2<i32>>(), Err(ParseVectorError::InvalidComponent { field: "y", .. })));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVectorError<E> {
+    /// The string has unbalanced delimiters, or labels its components with the wrong field names.
+    InvalidFormat,
+
+    /// The string contains a different number of components than the vector has fields.
+    ComponentCount { expected: usize, found: usize },
+
+    /// The component for `field` couldn't be parsed into the field type.
+    InvalidComponent { field: &'static str, error: E },
+}
+
+
+impl<E: fmt::Display> fmt::Display for ParseVectorError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => f.write_str("invalid vector format"),
+            Self::ComponentCount { expected, found } => write!(f, "expected {} components, found {}", expected, found),
+            Self::InvalidComponent { field, error } => write!(f, "invalid value for component `{}`: {}", field, error),
+        }
+    }
+}
+
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for ParseVectorError<E> {  }
+
+
+// Parses the components of a vector named `name` with the given `fields`, accepting `(1, 2)`, `1,2`,
+// `[1 2]` and the `Debug` form `Name { x: 1, y: 2 }`.
+pub(crate) fn parse_components<T: FromStr, const N: usize>(
+    string: &str,
+    name: &str,
+    fields: [&'static str; N],
+) -> Result<[T; N], ParseVectorError<T::Err>> {
+    let string = string.trim();
+
+    let (body, labelled) = if let Some(rest) = string.strip_prefix(name) {
+        (strip_delimiters(rest.trim_start(), '{', '}')?, true)
+    } else if string.ends_with('}') {
+        // NOTE: The `Debug` form of a different vector type.
+        return Err(ParseVectorError::InvalidFormat);
+    } else if string.starts_with('(') {
+        (strip_delimiters(string, '(', ')')?, false)
+    } else if string.starts_with('[') {
+        (strip_delimiters(string, '[', ']')?, false)
+    } else {
+        (string, false)
+    };
+
+    let mut values: [Option<T>; N] = core::array::from_fn(|_| None);
+    let mut found = 0;
+
+    let mut parse_component = |component: &str| {
+        let index = found;
+        found += 1;
+
+        if index >= N {
+            return Ok(());
+        }
+
+        let value = if labelled {
+            let (label, value) = component.split_once(':').ok_or(ParseVectorError::InvalidFormat)?;
+
+            if label.trim() != fields[index] {
+                return Err(ParseVectorError::InvalidFormat);
+            }
+
+            value
+        } else {
+            component
+        };
+
+        values[index] = Some(value.trim().parse().map_err(|error| {
+            ParseVectorError::InvalidComponent { field: fields[index], error }
+        })?);
+
+        Ok(())
+    };
+
+    // NOTE: A single trailing comma is allowed, matching the output of `{:#?}` and Rust's own syntax.
+    if body.contains(',') {
+        let body = body.trim_end();
+        body.strip_suffix(',').unwrap_or(body).split(',').try_for_each(&mut parse_component)?;
+    } else {
+        body.split_whitespace().try_for_each(&mut parse_component)?;
+    }
+
+    if found != N {
+        return Err(ParseVectorError::ComponentCount { expected: N, found });
+    }
+
+    Ok(values.map(|value| value.expect("every component was parsed")))
+}
+
+
+fn strip_delimiters<E>(string: &str, open: char, close: char) -> Result<&str, ParseVectorError<E>> {
+    string.strip_prefix(open)
+        .and_then(|string| string.strip_suffix(close))
+        .ok_or(ParseVectorError::InvalidFormat)
+}
\ No newline at end of file
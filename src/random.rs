@@ -0,0 +1,160 @@
+use crate::{Vector2, Vector3, Vector4, Vector3A, Vector4A};
+use crate::macros::floating::FloatingPoint;
+use num_traits::float::FloatCore;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard, Uniform};
+use rand::Rng;
+
+
+/// Distribution sampling [`Vector2`]s uniformly from the circumference of the unit circle.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{UnitCircle, Vector2};
+/// use rand::{rngs::SmallRng, Rng, SeedableRng};
+/// 
+/// let vec2: Vector2<f64> = SmallRng::seed_from_u64(0).sample(UnitCircle);
+/// 
+/// assert!(vec2.is_normalized());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UnitCircle;
+
+
+/// Distribution sampling [`Vector3`]s uniformly from the surface of the unit sphere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UnitSphere;
+
+
+/// Distribution sampling [`Vector2`]s uniformly from the area of the unit disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InsideUnitDisk;
+
+
+/// Distribution sampling [`Vector3`]s uniformly from the volume of the unit sphere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InsideUnitSphere;
+
+
+// Samples vectors from the cube spanning `-1..=1` until `accept` returns `true` for them.
+fn sample_unit_cube<V, R, F>(rng: &mut R, low: V, high: V, accept: F) -> V
+where
+    V: SampleUniform,
+    R: Rng + ?Sized,
+    F: Fn(&V) -> bool,
+{
+    let uniform = Uniform::new_inclusive(low, high);
+
+    loop {
+        let vector = uniform.sample(rng);
+
+        if accept(&vector) {
+            return vector;
+        }
+    }
+}
+
+
+macro_rules! impl_unit_distribution {
+    ( $struct: ident, $surface: ident, $inside: ident ) => {
+        impl<T> Distribution<$struct<T>> for $surface
+        where
+            T: FloatingPoint + FloatCore + SampleUniform,
+        {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $struct<T> {
+                // NOTE: Rejecting vectors outside of the unit sphere keeps the directions uniform,
+                // as the corners of the cube would otherwise be over-represented once normalized.
+                sample_unit_cube(rng, $struct::from_value(-T::one()), $struct::from_value(T::one()), |vector: &$struct<T>| {
+                    let length_squared = vector.length_squared();
+                    length_squared > T::epsilon() && length_squared <= T::one()
+                }).normalized()
+            }
+        }
+
+        impl<T> Distribution<$struct<T>> for $inside
+        where
+            T: FloatCore + SampleUniform,
+        {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $struct<T> {
+                sample_unit_cube(rng, $struct::from_value(-T::one()), $struct::from_value(T::one()), |vector: &$struct<T>| {
+                    vector.length_squared() <= T::one()
+                })
+            }
+        }
+    };
+}
+
+
+macro_rules! impl_random {
+    ( $struct: ident { $($field: ident), + }, $sampler: ident ) => {
+        impl<T> Distribution<$struct<T>> for Standard
+        where
+            Standard: Distribution<T>,
+        {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $struct<T> {
+                $struct {
+                    $( $field: rng.gen() ), +
+                }
+            }
+        }
+
+        #[doc = core::concat!("Sampler for uniformly distributed [`", core::stringify!($struct), "`]s, sampling each field independently.")]
+        /// 
+        /// Returned by [`Uniform::new`](rand::distributions::Uniform::new) and `Uniform::new_inclusive`,
+        /// whose bounds form an axis-aligned box.
+        #[derive(Debug, Clone, Copy)]
+        pub struct $sampler<T: SampleUniform> {
+            $( $field: T::Sampler ), +
+        }
+
+        impl<T: SampleUniform> SampleUniform for $struct<T> {
+            type Sampler = $sampler<T>;
+        }
+
+        impl<T: SampleUniform> UniformSampler for $sampler<T> {
+            type X = $struct<T>;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (low.borrow(), high.borrow());
+
+                Self {
+                    $( $field: T::Sampler::new(&low.$field, &high.$field) ), +
+                }
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (low.borrow(), high.borrow());
+
+                Self {
+                    $( $field: T::Sampler::new_inclusive(&low.$field, &high.$field) ), +
+                }
+            }
+
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                $struct {
+                    $( $field: self.$field.sample(rng) ), +
+                }
+            }
+        }
+    };
+}
+
+
+impl_random!(Vector2 { x, y }, UniformVector2);
+impl_random!(Vector3 { x, y, z }, UniformVector3);
+impl_random!(Vector4 { x, y, z, w }, UniformVector4);
+impl_random!(Vector3A { x, y, z }, UniformVector3A);
+impl_random!(Vector4A { x, y, z, w }, UniformVector4A);
+
+
+impl_unit_distribution!(Vector2, UnitCircle, InsideUnitDisk);
+impl_unit_distribution!(Vector3, UnitSphere, InsideUnitSphere);
\ No newline at end of file
@@ -0,0 +1,317 @@
+// NOTE: Newer toolchains allow calling the arithmetic intrinsics from safe code when the target feature
+// is statically enabled, older toolchains still require an `unsafe` block around them.
+#![allow(unused_unsafe)]
+
+#[cfg(all(target_arch = "x86", target_feature = "sse2"))]
+use core::arch::x86::*;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+use core::arch::x86_64::*;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use core::arch::aarch64::*;
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use core::arch::wasm32::*;
+
+
+/// Four packed `f32` lanes, backed by the widest SIMD register available on the target,
+/// or by a plain array when no supported instruction set is enabled.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub(crate) struct F32x4(Register);
+
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+type Register = __m128;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+type Register = float32x4_t;
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+type Register = v128;
+
+#[cfg(not(any(
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128"),
+)))]
+type Register = [f32; 4];
+
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+impl F32x4 {
+    #[inline(always)]
+    pub(crate) fn from_array(array: [f32; 4]) -> Self {
+        // SAFETY: `array` is valid for reads of four `f32`s, `_mm_loadu_ps` has no alignment requirement.
+        Self(unsafe { _mm_loadu_ps(array.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn to_array(self) -> [f32; 4] {
+        let mut array = [0.0; 4];
+        // SAFETY: `array` is valid for writes of four `f32`s, `_mm_storeu_ps` has no alignment requirement.
+        unsafe { _mm_storeu_ps(array.as_mut_ptr(), self.0) };
+        array
+    }
+
+    #[inline(always)]
+    pub(crate) fn splat(value: f32) -> Self {
+        Self(unsafe { _mm_set1_ps(value) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn add(self, other: Self) -> Self {
+        Self(unsafe { _mm_add_ps(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub(self, other: Self) -> Self {
+        Self(unsafe { _mm_sub_ps(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul(self, other: Self) -> Self {
+        Self(unsafe { _mm_mul_ps(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn div(self, other: Self) -> Self {
+        Self(unsafe { _mm_div_ps(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn neg(self) -> Self {
+        Self(unsafe { _mm_xor_ps(self.0, _mm_set1_ps(-0.0)) })
+    }
+
+    // NOTE: `_mm_min_ps` and `_mm_max_ps` return `other` when either lane is `NaN`,
+    // so lanes where `other` is `NaN` are replaced by `self` to match `f32::min`.
+    #[inline(always)]
+    pub(crate) fn min(self, other: Self) -> Self {
+        unsafe {
+            let nan = _mm_cmpunord_ps(other.0, other.0);
+            Self(_mm_or_ps(_mm_and_ps(nan, self.0), _mm_andnot_ps(nan, _mm_min_ps(self.0, other.0))))
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn max(self, other: Self) -> Self {
+        unsafe {
+            let nan = _mm_cmpunord_ps(other.0, other.0);
+            Self(_mm_or_ps(_mm_and_ps(nan, self.0), _mm_andnot_ps(nan, _mm_max_ps(self.0, other.0))))
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn sum(self) -> f32 {
+        unsafe {
+            let swapped = _mm_shuffle_ps(self.0, self.0, 0b10_11_00_01);
+            let pairs = _mm_add_ps(self.0, swapped);
+            let high = _mm_movehl_ps(swapped, pairs);
+            _mm_cvtss_f32(_mm_add_ss(pairs, high))
+        }
+    }
+}
+
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+impl F32x4 {
+    #[inline(always)]
+    pub(crate) fn from_array(array: [f32; 4]) -> Self {
+        // SAFETY: `array` is valid for reads of four `f32`s.
+        Self(unsafe { vld1q_f32(array.as_ptr()) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn to_array(self) -> [f32; 4] {
+        let mut array = [0.0; 4];
+        // SAFETY: `array` is valid for writes of four `f32`s.
+        unsafe { vst1q_f32(array.as_mut_ptr(), self.0) };
+        array
+    }
+
+    #[inline(always)]
+    pub(crate) fn splat(value: f32) -> Self {
+        Self(unsafe { vdupq_n_f32(value) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn add(self, other: Self) -> Self {
+        Self(unsafe { vaddq_f32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub(self, other: Self) -> Self {
+        Self(unsafe { vsubq_f32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul(self, other: Self) -> Self {
+        Self(unsafe { vmulq_f32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn div(self, other: Self) -> Self {
+        Self(unsafe { vdivq_f32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn neg(self) -> Self {
+        Self(unsafe { vnegq_f32(self.0) })
+    }
+
+    // NOTE: Unlike `vminq_f32` and `vmaxq_f32`, these return the lane that isn't `NaN`, matching `f32::min`.
+    #[inline(always)]
+    pub(crate) fn min(self, other: Self) -> Self {
+        Self(unsafe { vminnmq_f32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn max(self, other: Self) -> Self {
+        Self(unsafe { vmaxnmq_f32(self.0, other.0) })
+    }
+
+    #[inline(always)]
+    pub(crate) fn sum(self) -> f32 {
+        unsafe { vaddvq_f32(self.0) }
+    }
+}
+
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+impl F32x4 {
+    #[inline(always)]
+    pub(crate) fn from_array(array: [f32; 4]) -> Self {
+        Self(f32x4(array[0], array[1], array[2], array[3]))
+    }
+
+    #[inline(always)]
+    pub(crate) fn to_array(self) -> [f32; 4] {
+        [
+            f32x4_extract_lane::<0>(self.0),
+            f32x4_extract_lane::<1>(self.0),
+            f32x4_extract_lane::<2>(self.0),
+            f32x4_extract_lane::<3>(self.0),
+        ]
+    }
+
+    #[inline(always)]
+    pub(crate) fn splat(value: f32) -> Self {
+        Self(f32x4_splat(value))
+    }
+
+    #[inline(always)]
+    pub(crate) fn add(self, other: Self) -> Self {
+        Self(f32x4_add(self.0, other.0))
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub(self, other: Self) -> Self {
+        Self(f32x4_sub(self.0, other.0))
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul(self, other: Self) -> Self {
+        Self(f32x4_mul(self.0, other.0))
+    }
+
+    #[inline(always)]
+    pub(crate) fn div(self, other: Self) -> Self {
+        Self(f32x4_div(self.0, other.0))
+    }
+
+    #[inline(always)]
+    pub(crate) fn neg(self) -> Self {
+        Self(f32x4_neg(self.0))
+    }
+
+    // NOTE: `f32x4_pmin` and `f32x4_pmax` return `self` when either lane is `NaN`,
+    // so lanes where `self` is `NaN` are replaced by `other` to match `f32::min`.
+    #[inline(always)]
+    pub(crate) fn min(self, other: Self) -> Self {
+        Self(v128_bitselect(other.0, f32x4_pmin(self.0, other.0), f32x4_ne(self.0, self.0)))
+    }
+
+    #[inline(always)]
+    pub(crate) fn max(self, other: Self) -> Self {
+        Self(v128_bitselect(other.0, f32x4_pmax(self.0, other.0), f32x4_ne(self.0, self.0)))
+    }
+
+    #[inline(always)]
+    pub(crate) fn sum(self) -> f32 {
+        let [x, y, z, w] = self.to_array();
+        (x + y) + (z + w)
+    }
+}
+
+
+#[cfg(not(any(
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128"),
+)))]
+impl F32x4 {
+    #[inline(always)]
+    pub(crate) fn from_array(array: [f32; 4]) -> Self {
+        Self(array)
+    }
+
+    #[inline(always)]
+    pub(crate) fn to_array(self) -> [f32; 4] {
+        self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn splat(value: f32) -> Self {
+        Self([value; 4])
+    }
+
+    #[inline(always)]
+    pub(crate) fn add(self, other: Self) -> Self {
+        self.zip(other, |a, b| a + b)
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub(self, other: Self) -> Self {
+        self.zip(other, |a, b| a - b)
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul(self, other: Self) -> Self {
+        self.zip(other, |a, b| a * b)
+    }
+
+    #[inline(always)]
+    pub(crate) fn div(self, other: Self) -> Self {
+        self.zip(other, |a, b| a / b)
+    }
+
+    #[inline(always)]
+    pub(crate) fn neg(self) -> Self {
+        Self(self.0.map(|value| -value))
+    }
+
+    #[inline(always)]
+    pub(crate) fn min(self, other: Self) -> Self {
+        self.zip(other, f32::min)
+    }
+
+    #[inline(always)]
+    pub(crate) fn max(self, other: Self) -> Self {
+        self.zip(other, f32::max)
+    }
+
+    #[inline(always)]
+    pub(crate) fn sum(self) -> f32 {
+        let [x, y, z, w] = self.0;
+        (x + y) + (z + w)
+    }
+
+    #[inline(always)]
+    fn zip<F: Fn(f32, f32) -> f32>(self, other: Self, f: F) -> Self {
+        let [ax, ay, az, aw] = self.0;
+        let [bx, by, bz, bw] = other.0;
+        Self([f(ax, bx), f(ay, by), f(az, bz), f(aw, bw)])
+    }
+}
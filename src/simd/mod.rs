@@ -0,0 +1,392 @@
+mod lanes;
+
+use lanes::F32x4;
+use crate::{Vector3, Vector4, Vector3A, Vector4A};
+
+
+/// SIMD-accelerated vector for holding three-dimensional `f32` values,
+/// aligned to 16 bytes so it can be loaded into a single SIMD register.
+/// 
+/// Requires the `simd` feature, falling back to scalar code on targets without SSE2, NEON or `simd128`.
+/// Converts to and from [`Vector3<f32>`] and [`Vector3A<f32>`] through [`From`].
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::SimdVector3;
+/// 
+/// let mut vec3 = SimdVector3::new(1.0, 2.0, 3.0);
+/// vec3 += SimdVector3::new(1.0, 2.0, 3.0);
+/// 
+/// assert_eq!(vec3.x, 2.0);
+/// assert_eq!(vec3.y, 4.0);
+/// assert_eq!(vec3.z, 6.0);
+/// ```
+#[repr(C, align(16))]
+pub struct SimdVector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+
+/// SIMD-accelerated vector for holding four-dimensional `f32` values,
+/// aligned to 16 bytes so it can be loaded into a single SIMD register.
+/// 
+/// Requires the `simd` feature, falling back to scalar code on targets without SSE2, NEON or `simd128`.
+/// Converts to and from [`Vector4<f32>`] and [`Vector4A<f32>`] through [`From`].
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::SimdVector4;
+/// 
+/// let mut vec4 = SimdVector4::new(1.0, 2.0, 3.0, 4.0);
+/// vec4 += SimdVector4::new(1.0, 2.0, 3.0, 4.0);
+/// 
+/// assert_eq!(vec4.x, 2.0);
+/// assert_eq!(vec4.y, 4.0);
+/// assert_eq!(vec4.z, 6.0);
+/// assert_eq!(vec4.w, 8.0);
+/// ```
+#[repr(C, align(16))]
+pub struct SimdVector4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+
+impl SimdVector3 {
+    #[inline(always)]
+    fn to_lanes(self) -> F32x4 {
+        F32x4::from_array([self.x, self.y, self.z, 0.0])
+    }
+
+    #[inline(always)]
+    fn from_lanes(lanes: F32x4) -> Self {
+        let [x, y, z, _] = lanes.to_array();
+        Self { x, y, z }
+    }
+}
+
+
+impl SimdVector4 {
+    #[inline(always)]
+    fn to_lanes(self) -> F32x4 {
+        F32x4::from_array([self.x, self.y, self.z, self.w])
+    }
+
+    #[inline(always)]
+    fn from_lanes(lanes: F32x4) -> Self {
+        let [x, y, z, w] = lanes.to_array();
+        Self { x, y, z, w }
+    }
+}
+
+
+macro_rules! impl_simd_vector {
+    ( $struct: ident { $($field: ident), + }, $scalar: ident, $aligned: ident, $size: expr ) => {
+        impl $struct {
+            /// Constructs a new vector with the specified values for each field.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector4;
+            /// 
+            /// let vec4 = SimdVector4::new(1.0, 2.0, 3.0, 4.0);
+            /// 
+            /// assert_eq!(vec4.to_array(), [1.0, 2.0, 3.0, 4.0]);
+            /// ```
+            #[inline(always)]
+            pub const fn new( $($field: f32), + ) -> Self {
+                Self {
+                    $( $field ), +
+                }
+            }
+
+            /// Constructs a vector using the given `value` as the value for all of its fields.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector3;
+            /// 
+            /// assert_eq!(SimdVector3::from_value(2.0), SimdVector3::new(2.0, 2.0, 2.0));
+            /// ```
+            #[inline(always)]
+            pub const fn from_value(value: f32) -> Self {
+                Self {
+                    $( $field: value ), +
+                }
+            }
+
+            /// Consumes the vector and returns its values as an array.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector3;
+            /// 
+            /// assert_eq!(SimdVector3::new(1.0, 2.0, 3.0).to_array(), [1.0, 2.0, 3.0]);
+            /// ```
+            #[inline(always)]
+            pub fn to_array(self) -> [f32; $size] {
+                [ $(self.$field), + ]
+            }
+
+            /// Returns the dot product of two vectors.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector3;
+            /// 
+            /// let dot = SimdVector3::new(1.0, 2.0, 3.0).dot(&SimdVector3::new(4.0, 5.0, 6.0));
+            /// 
+            /// assert_eq!(dot, 32.0);
+            /// ```
+            #[inline]
+            pub fn dot(&self, other: &Self) -> f32 {
+                self.to_lanes().mul(other.to_lanes()).sum()
+            }
+
+            /// Returns the squared magnitude of vector.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector4;
+            /// 
+            /// assert_eq!(SimdVector4::new(1.0, 2.0, 2.0, 4.0).length_squared(), 25.0);
+            /// ```
+            #[inline]
+            pub fn length_squared(&self) -> f32 {
+                self.dot(self)
+            }
+
+            /// Returns the magnitude of the vector.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector4;
+            /// 
+            /// assert_eq!(SimdVector4::new(1.0, 2.0, 2.0, 4.0).length(), 5.0);
+            /// ```
+            #[inline]
+            pub fn length(&self) -> f32 {
+                libm::sqrtf(self.length_squared())
+            }
+
+            /// Consumes the vector and returns it as normalized vector,
+            /// or a zeroed vector if its magnitude is zero.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector3;
+            /// 
+            /// assert_eq!(SimdVector3::new(0.0, 3.0, 4.0).normalized(), SimdVector3::new(0.0, 0.6, 0.8));
+            /// assert_eq!(SimdVector3::from_value(0.0).normalized(), SimdVector3::from_value(0.0));
+            /// ```
+            #[inline]
+            pub fn normalized(self) -> Self {
+                let length_squared = self.length_squared();
+
+                if length_squared == 0.0 {
+                    return Self::from_value(0.0);
+                }
+
+                self / libm::sqrtf(length_squared)
+            }
+
+            /// Linearly interpolates between two Vectors by a normalized `weight`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector3;
+            /// 
+            /// let vec3 = SimdVector3::from_value(0.0).lerp(SimdVector3::new(2.0, 4.0, 8.0), 0.5);
+            /// 
+            /// assert_eq!(vec3, SimdVector3::new(1.0, 2.0, 4.0));
+            /// ```
+            #[inline]
+            pub fn lerp(self, to: Self, weight: f32) -> Self {
+                let from = self.to_lanes();
+                let delta = to.to_lanes().sub(from);
+
+                Self::from_lanes(from.add(delta.mul(F32x4::splat(weight))))
+            }
+
+            /// Returns a vector containing the minimum of each field within the two vectors. Like [`f32::min`],
+            /// a field that is `NaN` in only one of the vectors is replaced by the other vector's field on every target.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector3;
+            /// 
+            /// let vec3 = SimdVector3::new(1.0, f32::NAN, 5.0).min(SimdVector3::new(3.0, 2.0, f32::NAN));
+            /// 
+            /// assert_eq!(vec3, SimdVector3::new(1.0, 2.0, 5.0));
+            /// ```
+            #[inline]
+            pub fn min(self, other: Self) -> Self {
+                Self::from_lanes(self.to_lanes().min(other.to_lanes()))
+            }
+
+            /// Returns a vector containing the maximum of each field within the two vectors. Like [`f32::max`],
+            /// a field that is `NaN` in only one of the vectors is replaced by the other vector's field on every target.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::SimdVector3;
+            /// 
+            /// let vec3 = SimdVector3::new(1.0, f32::NAN, 5.0).max(SimdVector3::new(3.0, 2.0, f32::NAN));
+            /// 
+            /// assert_eq!(vec3, SimdVector3::new(3.0, 2.0, 5.0));
+            /// ```
+            #[inline]
+            pub fn max(self, other: Self) -> Self {
+                Self::from_lanes(self.to_lanes().max(other.to_lanes()))
+            }
+        }
+
+        impl From<$scalar<f32>> for $struct {
+            #[inline(always)]
+            fn from(from: $scalar<f32>) -> Self {
+                Self {
+                    $( $field: from.$field ), +
+                }
+            }
+        }
+
+        impl From<$struct> for $scalar<f32> {
+            #[inline(always)]
+            fn from(from: $struct) -> Self {
+                Self {
+                    $( $field: from.$field ), +
+                }
+            }
+        }
+
+        impl From<$aligned<f32>> for $struct {
+            #[inline(always)]
+            fn from(from: $aligned<f32>) -> Self {
+                Self {
+                    $( $field: from.$field ), +
+                }
+            }
+        }
+
+        impl From<$struct> for $aligned<f32> {
+            #[inline(always)]
+            fn from(from: $struct) -> Self {
+                Self {
+                    $( $field: from.$field ), +
+                }
+            }
+        }
+
+        impl From<[f32; $size]> for $struct {
+            #[inline(always)]
+            fn from(from: [f32; $size]) -> Self {
+                let [ $($field), + ] = from;
+
+                Self {
+                    $( $field ), +
+                }
+            }
+        }
+
+        impl core::fmt::Debug for $struct {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let identifier = core::stringify!($struct);
+
+                f.debug_struct(identifier)
+                    $( .field( core::stringify!($field), &self.$field ) ) +
+                    .finish()
+            }
+        }
+
+        impl PartialEq for $struct {
+            fn eq(&self, other: &Self) -> bool {
+                $( self.$field == other.$field ) && +
+            }
+        }
+
+        impl Clone for $struct {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl Copy for $struct {  }
+
+        impl Default for $struct {
+            fn default() -> Self {
+                Self::from_value(0.0)
+            }
+        }
+
+        impl core::ops::Neg for $struct {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self::from_lanes(self.to_lanes().neg())
+            }
+        }
+
+        impl_simd_operator!( $struct, Add, add, AddAssign, add_assign );
+        impl_simd_operator!( $struct, Sub, sub, SubAssign, sub_assign );
+        impl_simd_operator!( $struct, Mul, mul, MulAssign, mul_assign );
+        impl_simd_operator!( $struct, Div, div, DivAssign, div_assign );
+    };
+}
+
+
+macro_rules! impl_simd_operator {
+    ( $struct: ident, $trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident ) => {
+        impl core::ops::$trait<Self> for $struct {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, other: Self) -> Self::Output {
+                Self::from_lanes(self.to_lanes().$method(other.to_lanes()))
+            }
+        }
+
+        impl core::ops::$trait<f32> for $struct {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, other: f32) -> Self::Output {
+                Self::from_lanes(self.to_lanes().$method(F32x4::splat(other)))
+            }
+        }
+
+        impl core::ops::$assign_trait<Self> for $struct {
+            #[inline]
+            fn $assign_method(&mut self, other: Self) {
+                *self = core::ops::$trait::$method(*self, other);
+            }
+        }
+
+        impl core::ops::$assign_trait<f32> for $struct {
+            #[inline]
+            fn $assign_method(&mut self, other: f32) {
+                *self = core::ops::$trait::$method(*self, other);
+            }
+        }
+    };
+}
+
+
+impl_simd_vector!(SimdVector3 { x, y, z }, Vector3, Vector3A, 3);
+impl_simd_vector!(SimdVector4 { x, y, z, w }, Vector4, Vector4A, 4);
@@ -0,0 +1,406 @@
+use crate::{Vector2, Vector3, Vector4};
+use crate::macros::floating::FloatingPoint;
+use num_traits::float::FloatCore;
+
+
+/// Structure-of-arrays container holding `N` two-dimensional values, storing each axis contiguously
+/// so batched operations can be auto-vectorized.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Vector2, Vector2Soa};
+/// 
+/// let mut soa = Vector2Soa::from([Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)]);
+/// soa.scale(2.0);
+/// 
+/// assert_eq!(soa.x, [2.0, 6.0]);
+/// assert_eq!(soa.y, [4.0, 8.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector2Soa<T, const N: usize> {
+    pub x: [T; N],
+    pub y: [T; N],
+}
+
+
+/// Structure-of-arrays container holding `N` three-dimensional values, storing each axis contiguously
+/// so batched operations can be auto-vectorized.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Vector3, Vector3Soa};
+/// 
+/// let mut soa = Vector3Soa::from([Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)]);
+/// soa += Vector3Soa::from_value(Vector3::new(1.0, 1.0, 1.0));
+/// 
+/// assert_eq!(soa.get(1), Some(Vector3::new(5.0, 6.0, 7.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector3Soa<T, const N: usize> {
+    pub x: [T; N],
+    pub y: [T; N],
+    pub z: [T; N],
+}
+
+
+/// Structure-of-arrays container holding `N` four-dimensional values, storing each axis contiguously
+/// so batched operations can be auto-vectorized.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{Vector4, Vector4Soa};
+/// 
+/// let soa = Vector4Soa::from([Vector4::new(1.0, 0.0, 0.0, 0.0), Vector4::new(0.0, 3.0, 0.0, 4.0)]);
+/// 
+/// assert_eq!(soa.length(), [1.0, 5.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector4Soa<T, const N: usize> {
+    pub x: [T; N],
+    pub y: [T; N],
+    pub z: [T; N],
+    pub w: [T; N],
+}
+
+
+macro_rules! impl_soa {
+    ( $soa: ident, $struct: ident { $($field: ident), + } ) => {
+        impl<T, const N: usize> $soa<T, N> {
+            /// Constructs a new container from the given arrays of values for each axis.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// let soa = Vector2Soa::new([1, 2], [3, 4]);
+            /// 
+            /// assert_eq!(soa.x, [1, 2]);
+            /// assert_eq!(soa.y, [3, 4]);
+            /// ```
+            #[inline(always)]
+            pub const fn new( $($field: [T; N]), + ) -> Self {
+                Self {
+                    $( $field ), +
+                }
+            }
+
+            /// Returns the number of vectors held within the container.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// assert_eq!(Vector2Soa::new([1, 2, 3], [4, 5, 6]).len(), 3);
+            /// ```
+            #[inline(always)]
+            pub const fn len(&self) -> usize {
+                N
+            }
+
+            /// Returns `true` if the container holds no vectors.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// assert!(Vector2Soa::<f32, 0>::new([], []).is_empty());
+            /// assert!(!Vector2Soa::new([1.0], [2.0]).is_empty());
+            /// ```
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                N == 0
+            }
+        }
+
+        impl<T: Copy, const N: usize> $soa<T, N> {
+            /// Constructs a container holding `N` copies of the given `vector`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Vector2, Vector2Soa};
+            /// 
+            /// let soa = Vector2Soa::<_, 3>::from_value(Vector2::new(1, 2));
+            /// 
+            /// assert_eq!(soa, Vector2Soa::new([1, 1, 1], [2, 2, 2]));
+            /// ```
+            #[inline]
+            pub fn from_value(vector: $struct<T>) -> Self {
+                Self {
+                    $( $field: [vector.$field; N] ), +
+                }
+            }
+
+            /// Returns the vector at the given `index`, or [`None`] if it is out of bounds.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Vector2, Vector2Soa};
+            /// 
+            /// let soa = Vector2Soa::new([1, 2], [3, 4]);
+            /// 
+            /// assert_eq!(soa.get(1), Some(Vector2::new(2, 4)));
+            /// assert_eq!(soa.get(2), None);
+            /// ```
+            #[inline]
+            pub fn get(&self, index: usize) -> Option<$struct<T>> {
+                if index >= N {
+                    return None;
+                }
+
+                Some($struct {
+                    $( $field: self.$field[index] ), +
+                })
+            }
+
+            /// Replaces the vector at the given `index`.
+            /// 
+            /// # Panics
+            /// 
+            /// Panics if `index` is out of bounds.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Vector2, Vector2Soa};
+            /// 
+            /// let mut soa = Vector2Soa::new([1, 2], [3, 4]);
+            /// soa.set(0, Vector2::new(5, 6));
+            /// 
+            /// assert_eq!(soa, Vector2Soa::new([5, 2], [6, 4]));
+            /// ```
+            #[inline]
+            pub fn set(&mut self, index: usize, vector: $struct<T>) {
+                $( self.$field[index] = vector.$field; ) +
+            }
+
+            /// Returns an iterator over the vectors held within the container.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Vector2, Vector2Soa};
+            /// 
+            /// let soa = Vector2Soa::new([1, 2], [3, 4]);
+            /// let mut iter = soa.iter();
+            /// 
+            /// assert_eq!(iter.next(), Some(Vector2::new(1, 3)));
+            /// assert_eq!(iter.next(), Some(Vector2::new(2, 4)));
+            /// assert_eq!(iter.next(), None);
+            /// ```
+            #[inline]
+            pub fn iter(&self) -> impl Iterator<Item = $struct<T>> + '_ {
+                (0..N).map(move |index| $struct {
+                    $( $field: self.$field[index] ), +
+                })
+            }
+
+            /// Consumes the container and returns its values as an array of vectors.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::{Vector2, Vector2Soa};
+            /// 
+            /// let array = Vector2Soa::new([1, 2], [3, 4]).to_array();
+            /// 
+            /// assert_eq!(array, [Vector2::new(1, 3), Vector2::new(2, 4)]);
+            /// ```
+            #[inline]
+            pub fn to_array(self) -> [$struct<T>; N] {
+                core::array::from_fn(|index| $struct {
+                    $( $field: self.$field[index] ), +
+                })
+            }
+        }
+
+        impl<T: FloatCore, const N: usize> $soa<T, N> {
+            /// Multiplies every vector within the container by `factor`.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// let mut soa = Vector2Soa::new([1.0, 2.0], [3.0, 4.0]);
+            /// soa.scale(0.5);
+            /// 
+            /// assert_eq!(soa, Vector2Soa::new([0.5, 1.0], [1.5, 2.0]));
+            /// ```
+            #[inline]
+            pub fn scale(&mut self, factor: T) {
+                $(
+                    for value in self.$field.iter_mut() {
+                        *value = *value * factor;
+                    }
+                ) +
+            }
+
+            /// Returns the dot product of each pair of vectors within the two containers.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// let a = Vector2Soa::new([1.0, 2.0], [3.0, 4.0]);
+            /// let b = Vector2Soa::new([2.0, 0.0], [1.0, 1.0]);
+            /// 
+            /// assert_eq!(a.dot(&b), [5.0, 4.0]);
+            /// ```
+            #[inline]
+            pub fn dot(&self, other: &Self) -> [T; N] {
+                let mut dot = [T::zero(); N];
+
+                $(
+                    for ((dot, a), b) in dot.iter_mut().zip(self.$field.iter()).zip(other.$field.iter()) {
+                        *dot = *dot + (*a * *b);
+                    }
+                ) +
+
+                dot
+            }
+
+            /// Returns the squared magnitude of each vector within the container.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// let soa = Vector2Soa::new([3.0, 0.0], [4.0, 2.0]);
+            /// 
+            /// assert_eq!(soa.length_squared(), [25.0, 4.0]);
+            /// ```
+            #[inline]
+            pub fn length_squared(&self) -> [T; N] {
+                self.dot(self)
+            }
+        }
+
+        impl<T: FloatingPoint + FloatCore, const N: usize> $soa<T, N> {
+            /// Returns the magnitude of each vector within the container.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// let soa = Vector2Soa::new([3.0, 0.0], [4.0, 2.0]);
+            /// 
+            /// assert_eq!(soa.length(), [5.0, 2.0]);
+            /// ```
+            #[inline]
+            pub fn length(&self) -> [T; N] {
+                self.length_squared().map(T::sqrt)
+            }
+
+            #[doc = core::concat!(
+                "Normalizes every vector within the container through mutation, zeroing vectors with a magnitude of zero ",
+                "like [`", core::stringify!($struct), "::normalize`](crate::", core::stringify!($struct), "::normalize)."
+            )]
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// let mut soa = Vector2Soa::new([0.0, 0.0], [2.0, 0.0]);
+            /// soa.normalize();
+            /// 
+            /// assert_eq!(soa, Vector2Soa::new([0.0, 0.0], [1.0, 0.0]));
+            /// ```
+            pub fn normalize(&mut self) {
+                let length = self.length();
+
+                $(
+                    for (value, length) in self.$field.iter_mut().zip(length.iter()) {
+                        *value = if *length == T::zero() { T::zero() } else { *value / *length };
+                    }
+                ) +
+            }
+
+            /// Consumes the container and returns it with every vector normalized.
+            /// 
+            /// # Example
+            /// 
+            /// ```
+            /// use fixed_vectors::Vector2Soa;
+            /// 
+            /// let soa = Vector2Soa::new([3.0, 0.0], [4.0, 0.0]).normalized();
+            /// 
+            /// assert_eq!(soa, Vector2Soa::new([0.6, 0.0], [0.8, 0.0]));
+            /// ```
+            #[inline]
+            pub fn normalized(mut self) -> Self {
+                self.normalize();
+                self
+            }
+        }
+
+        impl<T: Copy + Default, const N: usize> Default for $soa<T, N> {
+            fn default() -> Self {
+                Self {
+                    $( $field: [T::default(); N] ), +
+                }
+            }
+        }
+
+        impl<T: Copy, const N: usize> From<[$struct<T>; N]> for $soa<T, N> {
+            fn from(from: [$struct<T>; N]) -> Self {
+                Self {
+                    $( $field: core::array::from_fn(|index| from[index].$field) ), +
+                }
+            }
+        }
+
+        impl<T: Copy, const N: usize> From<$soa<T, N>> for [$struct<T>; N] {
+            #[inline]
+            fn from(from: $soa<T, N>) -> Self {
+                from.to_array()
+            }
+        }
+
+        impl_soa_operator!( $soa { $($field), + }, Add, add, AddAssign, add_assign );
+        impl_soa_operator!( $soa { $($field), + }, Sub, sub, SubAssign, sub_assign );
+        impl_soa_operator!( $soa { $($field), + }, Mul, mul, MulAssign, mul_assign );
+        impl_soa_operator!( $soa { $($field), + }, Div, div, DivAssign, div_assign );
+    };
+}
+
+
+macro_rules! impl_soa_operator {
+    ( $soa: ident { $($field: ident), + }, $trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident ) => {
+        impl<T: core::ops::$assign_trait + Copy, const N: usize> core::ops::$assign_trait for $soa<T, N> {
+            fn $assign_method(&mut self, other: Self) {
+                $(
+                    for (a, b) in self.$field.iter_mut().zip(other.$field) {
+                        a.$assign_method(b);
+                    }
+                ) +
+            }
+        }
+
+        impl<T: core::ops::$assign_trait + Copy, const N: usize> core::ops::$trait for $soa<T, N> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, other: Self) -> Self::Output {
+                core::ops::$assign_trait::$assign_method(&mut self, other);
+                self
+            }
+        }
+    };
+}
+
+
+impl_soa!(Vector2Soa, Vector2 { x, y });
+impl_soa!(Vector3Soa, Vector3 { x, y, z });
+impl_soa!(Vector4Soa, Vector4 { x, y, z, w });
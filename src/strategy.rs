@@ -0,0 +1,56 @@
+//! [`proptest`] strategies for generating vectors with well-behaved floating-point fields.
+//! 
+//! # Example
+//! 
+//! ```
+//! use fixed_vectors::strategy;
+//! use proptest::strategy::{Strategy, ValueTree};
+//! use proptest::test_runner::TestRunner;
+//! 
+//! let mut runner = TestRunner::deterministic();
+//! let vec3 = strategy::normalized_vector3::<f64>().new_tree(&mut runner).unwrap().current();
+//! 
+//! assert!(vec3.is_normalized());
+//! ```
+
+use crate::{Vector2, Vector3, Vector4};
+use crate::macros::floating::FloatingPoint;
+use core::fmt::Debug;
+use core::ops::RangeInclusive;
+use num_traits::float::FloatCore;
+use proptest::strategy::Strategy;
+
+
+macro_rules! impl_strategy {
+    ( $struct: ident { $($field: ident), + }, $finite: ident, $normalized: ident ) => {
+        #[doc = core::concat!("Generates [`", core::stringify!($struct), "`]s with every field drawn from `range`, which keeps them finite.")]
+        pub fn $finite<T>(range: RangeInclusive<T>) -> impl Strategy<Value = $struct<T>>
+        where
+            T: Debug + Clone,
+            RangeInclusive<T>: Strategy<Value = T>,
+        {
+            ( $( { let $field = range.clone(); $field } ), + ).prop_map($struct::from)
+        }
+
+        #[doc = core::concat!("Generates [`", core::stringify!($struct), "`]s with a length of `1`, pointing in uniformly distributed directions.")]
+        pub fn $normalized<T>() -> impl Strategy<Value = $struct<T>>
+        where
+            T: FloatingPoint + FloatCore + Debug,
+            RangeInclusive<T>: Strategy<Value = T>,
+        {
+            // NOTE: Vectors outside of the unit sphere are rejected so the directions stay uniform,
+            // and vectors close to zero are rejected as their direction is dominated by rounding.
+            $finite(-T::one()..=T::one()).prop_filter_map("vector must be within the unit sphere", |vector| {
+                let length_squared = vector.length_squared();
+                let minimum = num_traits::cast(1e-6).unwrap_or_else(T::epsilon);
+
+                (length_squared > minimum && length_squared <= T::one()).then(|| vector.normalized())
+            })
+        }
+    };
+}
+
+
+impl_strategy!(Vector2 { x, y }, finite_vector2, normalized_vector2);
+impl_strategy!(Vector3 { x, y, z }, finite_vector3, normalized_vector3);
+impl_strategy!(Vector4 { x, y, z, w }, finite_vector4, normalized_vector4);
\ No newline at end of file
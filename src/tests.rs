@@ -1,4 +1,8 @@
+extern crate std;
+
 use super::*;
+use std::format;
+use std::string::ToString;
 
 
 #[test]
@@ -308,4 +312,1215 @@ fn rem_assign() {
     assert_eq!(vec4, Vector4::new(1, 0, 1, 0));
     assert_eq!(vec3, Vector3::new(1, 0, 1));
     assert_eq!(vec2, Vector2::new(1, 0));
+}
+
+
+#[test]
+fn constants() {
+    assert_eq!(Vector4::<i32>::ZERO, Vector4::new(0, 0, 0, 0));
+    assert_eq!(Vector3::<u8>::ONE, Vector3::new(1, 1, 1));
+    assert_eq!(Vector2::<f32>::NEG_ONE, Vector2::new(-1.0, -1.0));
+
+    assert_eq!(Vector4::<f64>::W, Vector4::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(Vector3::<i16>::NEG_Z, Vector3::new(0, 0, -1));
+    assert_eq!(Vector2::<u32>::Y, Vector2::new(0, 1));
+
+    assert_eq!(Vector2::<i8>::MIN, Vector2::new(i8::MIN, i8::MIN));
+    assert_eq!(Vector2::<u64>::MAX, Vector2::new(u64::MAX, u64::MAX));
+    assert_eq!(Vector2::<f32>::INFINITY, Vector2::from_value(f32::INFINITY));
+    assert!(Vector2::<f64>::NAN.x.is_nan());
+}
+
+
+#[test]
+fn zero_one() {
+    use num_traits::{Zero, One};
+
+    assert_eq!(Vector3::<i32>::zero(), Vector3::<i32>::ZERO);
+    assert_eq!(Vector3::<f32>::one(), Vector3::<f32>::ONE);
+
+    assert!(Vector2::new(0.0, 0.0).is_zero());
+    assert!(!Vector2::new(0, 1).is_zero());
+}
+
+
+#[test]
+fn float_classification() {
+    assert!(Vector3::new(1.0, 2.0, 3.0).is_finite());
+    assert!(!Vector3::new(1.0, f32::NEG_INFINITY, 3.0).is_finite());
+
+    assert!(Vector4::new(1.0, 2.0, 3.0, f64::NAN).is_nan());
+    assert_eq!(Vector3::new(f32::NAN, 1.0, f32::NAN).is_nan_mask(), Vector3::new(true, false, true));
+
+    assert!(Vector3::new(1.0, 1.0, 1.0).normalized().is_normalized());
+    assert!(!Vector3::new(1.0, 1.0, 1.0).is_normalized());
+}
+
+
+#[test]
+fn try_normalize() {
+    assert_eq!(Vector2::new(0.0, 5.0).try_normalize(), Some(Vector2::new(0.0, 1.0)));
+    assert_eq!(Vector3::new(0.0, 0.0, 0.0).try_normalize(), None);
+    assert_eq!(Vector3::new(f32::INFINITY, 0.0, 0.0).try_normalize(), None);
+
+    assert_eq!(Vector2::new(0.0, 0.0).normalize_or(Vector2::new(0.0, 1.0)), Vector2::new(0.0, 1.0));
+    assert_eq!(Vector2::new(f64::NAN, 0.0).normalize_or_zero(), Vector2::new(0.0, 0.0));
+}
+
+
+#[test]
+fn clamp_length() {
+    assert_eq!(Vector2::new(0.0, 2.0).with_length(5.0), Vector2::new(0.0, 5.0));
+    assert_eq!(Vector3::new(0.0, 0.0, 0.0).with_length(5.0), Vector3::new(0.0, 0.0, 0.0));
+
+    assert_eq!(Vector3::new(0.0, 0.0, 0.5).clamp_length(1.0, 2.0), Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(Vector3::new(0.0, 0.0, 1.5).clamp_length(1.0, 2.0), Vector3::new(0.0, 0.0, 1.5));
+    assert_eq!(Vector3::new(0.0, 0.0, 3.0).clamp_length(1.0, 2.0), Vector3::new(0.0, 0.0, 2.0));
+
+    assert_eq!(Vector4::new(4.0, 0.0, 0.0, 0.0).clamp_length_max(2.0), Vector4::new(2.0, 0.0, 0.0, 0.0));
+    assert_eq!(Vector4::new(1.0, 0.0, 0.0, 0.0).clamp_length_min(2.0), Vector4::new(2.0, 0.0, 0.0, 0.0));
+    assert_eq!(Vector2::new(1.0, 0.0).limit(2.0), Vector2::new(1.0, 0.0));
+}
+
+
+#[test]
+fn move_towards() {
+    let target = Vector3::new(10.0, 0.0, 0.0);
+    let mut position = Vector3::new(0.0, 0.0, 0.0);
+
+    position = position.move_towards(target, 3.0);
+    assert_eq!(position, Vector3::new(3.0, 0.0, 0.0));
+
+    position = position.move_towards(target, 100.0);
+    assert_eq!(position, target);
+}
+
+
+#[test]
+fn smoothstep() {
+    let edge0 = Vector3::new(0.0, 0.0, 0.0);
+    let edge1 = Vector3::new(1.0, 2.0, 4.0);
+
+    assert_eq!(Vector3::new(-1.0, 1.0, 8.0).smoothstep(edge0, edge1), Vector3::new(0.0, 0.5, 1.0));
+    assert_eq!(Vector3::new(-1.0, 1.0, 8.0).smootherstep(edge0, edge1), Vector3::new(0.0, 0.5, 1.0));
+    assert_eq!(Vector2::new(0.25, 0.75).smoothstep(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)), Vector2::new(0.15625, 0.84375));
+}
+
+
+#[test]
+fn inverse_lerp_remap() {
+    let from = Vector3::new(0.0, 10.0, -4.0);
+    let to = Vector3::new(2.0, 20.0, 4.0);
+    let weights = from.inverse_lerp(to, Vector3::new(1.0, 12.5, 0.0));
+
+    assert_eq!(weights, Vector3::new(0.5, 0.25, 0.5));
+    assert_eq!(from.lerp(to, 0.5), Vector3::new(1.0, 15.0, 0.0));
+
+    let remapped = Vector2::new(5.0, -1.0).remap(
+        Vector2::new(0.0, -2.0), Vector2::new(10.0, 2.0),
+        Vector2::new(0.0, 0.0), Vector2::new(1.0, 100.0),
+    );
+
+    assert_eq!(remapped, Vector2::new(0.5, 25.0));
+}
+
+
+#[test]
+fn bezier() {
+    let from = Vector3::new(0.0, 0.0, 0.0);
+    let to = Vector3::new(4.0, 0.0, 0.0);
+    let control = Vector3::new(2.0, 4.0, 0.0);
+
+    assert_eq!(from.quadratic_bezier(control, to, 0.0), from);
+    assert_eq!(from.quadratic_bezier(control, to, 1.0), to);
+    assert_eq!(from.quadratic_bezier(control, to, 0.5), Vector3::new(2.0, 2.0, 0.0));
+
+    assert_eq!(from.cubic_bezier(control, control, to, 0.0), from);
+    assert_eq!(from.cubic_bezier(control, control, to, 1.0), to);
+    assert_eq!(from.cubic_bezier(control, control, to, 0.5), Vector3::new(2.0, 3.0, 0.0));
+}
+
+
+#[test]
+fn hermite_catmull_rom() {
+    let from = Vector2::new(0.0, 0.0);
+    let to = Vector2::new(1.0, 1.0);
+    let tangent = Vector2::new(0.0, 0.0);
+
+    assert_eq!(from.hermite(tangent, to, tangent, 0.0), from);
+    assert_eq!(from.hermite(tangent, to, tangent, 1.0), to);
+    assert_eq!(from.hermite(tangent, to, tangent, 0.5), Vector2::new(0.5, 0.5));
+
+    let points = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(3.0, 1.0),
+    ];
+
+    assert_eq!(points[1].catmull_rom(points[0], points[2], points[3], 0.0), points[1]);
+    assert_eq!(points[1].catmull_rom(points[0], points[2], points[3], 1.0), points[2]);
+
+    for (index, point) in points.iter().enumerate() {
+        let weight = index as f64 / 3.0;
+        let sampled = Vector2::catmull_rom_spline(&points, weight).unwrap();
+
+        assert!((sampled - *point).length() < 1e-9);
+    }
+
+    assert_eq!(Vector2::<f32>::catmull_rom_spline(&[], 0.5), None);
+    assert_eq!(Vector2::catmull_rom_spline(&points[..1], 0.5), Some(points[0]));
+}
+
+
+#[test]
+fn slerp_nlerp() {
+    let from = Vector3::new(2.0_f64, 0.0, 0.0);
+    let to = Vector3::new(0.0, 2.0, 0.0);
+
+    let halfway = from.slerp(to, 0.5);
+    assert!((halfway.length() - 2.0).abs() < 1e-6);
+    assert!((halfway.x - halfway.y).abs() < 1e-6);
+
+    assert!((from.slerp(to, 0.0) - from).length() < 1e-6);
+    assert!((from.slerp(to, 1.0) - to).length() < 1e-6);
+    assert_eq!(from.slerp(from, 0.5), from);
+
+    let opposite = from.slerp(-from * 2.0, 0.5);
+    assert!((opposite.length() - 3.0).abs() < 1e-6);
+    assert!(opposite.dot(&from).abs() < 1e-6);
+    assert!((from.slerp(-from, 1.0) + from).length() < 1e-6);
+    assert!((Vector2::new(0.0, 1.0).slerp(Vector2::new(0.0, -1.0), 0.5) - Vector2::new(1.0, 0.0)).length() < 1e-6);
+
+    let nlerped = from.nlerp(to, 0.5);
+    assert!((nlerped.length() - 1.0).abs() < 1e-6);
+}
+
+
+#[test]
+fn mul_add() {
+    let vec4 = Vector4::new(1.0_f32, 2.0, 3.0, 4.0).mul_add(Vector4::from_value(2.0), Vector4::from_value(0.5));
+    assert_eq!(vec4, Vector4::new(2.5, 4.5, 6.5, 8.5));
+
+    // NOTE: `0.1 * 10.0 - 1.0` is `0.0` when rounded twice, but not when fused.
+    let fused = Vector2::new(0.1_f64, 0.1).mul_add(Vector2::from_value(10.0), Vector2::from_value(-1.0));
+    assert_eq!(fused, Vector2::from_value(libm::fma(0.1, 10.0, -1.0)));
+    assert_ne!(fused.x, 0.0);
+
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(4.0, 5.0, 6.0);
+    assert_eq!(a.dot_fma(&b), a.dot(&b));
+}
+
+
+#[test]
+fn recip_copysign_rem_euclid_exp2() {
+    assert_eq!(Vector3::new(1.0, 2.0, -4.0).recip(), Vector3::new(1.0, 0.5, -0.25));
+    assert_eq!(Vector3::new(1.0, -2.0, 3.0).copysign(Vector3::new(-0.0, 1.0, -5.0)), Vector3::new(-1.0, 2.0, -3.0));
+    assert_eq!(Vector3::new(5.5, -5.5, 4.0).rem_euclid(Vector3::new(2.0, 2.0, 2.0)), Vector3::new(1.5, 0.5, 0.0));
+    assert_eq!(Vector3::new(0.0_f32, 1.0, 10.0).exp2(), Vector3::new(1.0, 2.0, 1024.0));
+}
+
+
+#[cfg(feature = "simd")]
+#[test]
+fn simd_vectors() {
+    let a = SimdVector4::new(1.0, 2.0, 3.0, 4.0);
+    let b = SimdVector4::new(4.0, 3.0, 2.0, 1.0);
+
+    assert_eq!(a + b, SimdVector4::from_value(5.0));
+    assert_eq!(a - b, SimdVector4::new(-3.0, -1.0, 1.0, 3.0));
+    assert_eq!(a * 2.0, SimdVector4::new(2.0, 4.0, 6.0, 8.0));
+    assert_eq!(a / b, SimdVector4::new(0.25, 2.0 / 3.0, 1.5, 4.0));
+    assert_eq!(-a, SimdVector4::new(-1.0, -2.0, -3.0, -4.0));
+    assert_eq!(a.min(b), SimdVector4::new(1.0, 2.0, 2.0, 1.0));
+    assert_eq!(a.max(b), SimdVector4::new(4.0, 3.0, 3.0, 4.0));
+
+    let scalar = Vector4::from(a);
+    assert_eq!(a.dot(&b), scalar.dot(&Vector4::from(b)));
+    assert_eq!(a.length(), scalar.length());
+    assert_eq!(Vector4::from(a.normalized()), scalar.normalized());
+    assert_eq!(Vector4::from(a.lerp(b, 0.5)), scalar.lerp(Vector4::from(b), 0.5));
+
+    let vec3 = SimdVector3::new(3.0, 0.0, 4.0);
+    assert_eq!(core::mem::align_of::<SimdVector3>(), 16);
+    assert_eq!(vec3.length(), 5.0);
+    assert_eq!(vec3 / SimdVector3::from_value(2.0), SimdVector3::new(1.5, 0.0, 2.0));
+    assert_eq!(Vector3::from(vec3.normalized()), Vector3::new(0.6, 0.0, 0.8));
+    assert_eq!(SimdVector3::default().normalized(), SimdVector3::from_value(0.0));
+
+    let negated = (-SimdVector4::new(0.0, -0.0, 1.0, 0.0)).to_array();
+    assert_eq!(negated.map(f32::is_sign_negative), [true, false, true, true]);
+
+    // NOTE: Like `f32::min` and `f32::max`, fields that are `NaN` in only one vector are replaced by the other's.
+    let left = SimdVector4::new(f32::NAN, 1.0, f32::NAN, 2.0);
+    let right = SimdVector4::new(1.0, f32::NAN, f32::NAN, 3.0);
+    let (min, max) = (left.min(right).to_array(), left.max(right).to_array());
+
+    assert_eq!((min[0], min[1], min[3]), (1.0, 1.0, 2.0));
+    assert_eq!((max[0], max[1], max[3]), (1.0, 1.0, 3.0));
+    assert!(min[2].is_nan() && max[2].is_nan());
+
+    assert_eq!(SimdVector3::from(Vector3A::new(1.0, 2.0, 3.0)), SimdVector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector4A::from(a), Vector4A::new(1.0, 2.0, 3.0, 4.0));
+}
+
+
+#[test]
+fn aligned_vectors() {
+    assert_eq!(core::mem::align_of::<Vector3A<f32>>(), 16);
+    assert_eq!(core::mem::size_of::<Vector3A<f32>>(), 16);
+    assert_eq!(core::mem::align_of::<Vector4A<u8>>(), 16);
+
+    let vec3 = Vector3A::from(Vector3::new(1.0_f64, 2.0, 3.0));
+    assert!(vec3.normalized().is_normalized());
+    assert_eq!(Vector3::from(vec3 * 2.0), Vector3::new(2.0, 4.0, 6.0));
+
+    let vec4 = Vector4A::<i32>::ONE + Vector4A::<i32>::W;
+    assert_eq!(Vector4::from(vec4), Vector4::new(1, 1, 1, 2));
+    assert_eq!(vec4.to_array(), [1, 1, 1, 2]);
+}
+
+
+#[test]
+fn soa() {
+    let vectors = [
+        Vector3::new(3.0, 0.0, 4.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 2.0, 2.0),
+    ];
+
+    let mut soa = Vector3Soa::from(vectors);
+    assert_eq!(soa.len(), 3);
+    assert_eq!(soa.to_array(), vectors);
+    assert_eq!(soa.length(), [5.0, 0.0, 3.0]);
+    assert_eq!(soa.dot(&Vector3Soa::from_value(Vector3::new(1.0, 1.0, 1.0))), [7.0, 0.0, 5.0]);
+
+    soa.normalize();
+    for (normalized, vector) in soa.iter().zip(vectors) {
+        assert_eq!(normalized, vector.normalized());
+    }
+
+    soa += Vector3Soa::from_value(Vector3::new(1.0, 1.0, 1.0));
+    soa.scale(2.0);
+    assert_eq!(soa.get(1), Some(Vector3::new(2.0, 2.0, 2.0)));
+    assert_eq!(soa.get(3), None);
+
+    soa.set(0, Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(<[Vector3<f64>; 3]>::from(soa)[0], Vector3::new(0.0, 0.0, 0.0));
+
+    let soa = Vector2Soa::new([1, 2], [3, 4]) - Vector2Soa::from_value(Vector2::new(1, 1));
+    assert_eq!(soa, Vector2Soa::new([0, 1], [2, 3]));
+}
+
+
+#[test]
+fn min_max_clamp() {
+    let a = Vector3::new(1.0, 5.0, -2.0);
+    let b = Vector3::new(2.0, 3.0, -4.0);
+
+    assert_eq!(a.min_by_component(b), Vector3::new(1.0, 3.0, -4.0));
+    assert_eq!(a.max_by_component(b), Vector3::new(2.0, 5.0, -2.0));
+    assert_eq!(Vector4::new(-5, 0, 5, 10).clamp_by_component(Vector4::from_value(0), Vector4::from_value(6)), Vector4::new(0, 0, 5, 6));
+}
+
+
+#[test]
+fn aabb2() {
+    let aabb = Aabb2::from_points(&[Vector2::new(1, 5), Vector2::new(-2, 0), Vector2::new(3, 2)]).unwrap();
+    assert_eq!(aabb, Aabb2::new(Vector2::new(-2, 0), Vector2::new(3, 5)));
+    assert_eq!(Aabb2::<i32>::from_points(&[]), None);
+
+    assert!(aabb.contains(Vector2::new(3, 5)));
+    assert!(!aabb.contains(Vector2::new(4, 5)));
+    assert_eq!(aabb.size(), Vector2::new(5, 5));
+    assert_eq!(aabb.area(), 25);
+    assert_eq!(aabb.closest_point(Vector2::new(10, -10)), Vector2::new(3, 0));
+    assert_eq!(aabb.expand(1), Aabb2::new(Vector2::new(-3, -1), Vector2::new(4, 6)));
+    assert_eq!(aabb.corners(), [Vector2::new(-2, 0), Vector2::new(3, 0), Vector2::new(3, 5), Vector2::new(-2, 5)]);
+
+    let other = Aabb2::new(Vector2::new(2, 4), Vector2::new(6, 8));
+    assert!(aabb.intersects(&other));
+    assert_eq!(aabb.intersection(&other), Some(Aabb2::new(Vector2::new(2, 4), Vector2::new(3, 5))));
+    assert_eq!(aabb.union(&other), Aabb2::new(Vector2::new(-2, 0), Vector2::new(6, 8)));
+    assert!(aabb.union(&other).contains_aabb(&other));
+
+    let disjoint = Aabb2::new(Vector2::new(10, 10), Vector2::new(11, 11));
+    assert!(!aabb.intersects(&disjoint));
+    assert_eq!(aabb.intersection(&disjoint), None);
+}
+
+
+#[test]
+fn aabb3() {
+    let aabb = Aabb3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 4.0, 6.0));
+
+    assert_eq!(aabb.center(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(aabb.volume(), 48.0);
+    assert_eq!(aabb.closest_point(Vector3::new(1.0, 5.0, -1.0)), Vector3::new(1.0, 4.0, 0.0));
+
+    let corners = aabb.corners();
+    assert_eq!(corners[0], aabb.min);
+    assert_eq!(corners[7], aabb.max);
+    assert_eq!(corners[5], Vector3::new(2.0, 0.0, 6.0));
+    assert_eq!(Aabb3::from_points(&corners), Some(aabb));
+}
+
+
+#[test]
+fn cross() {
+    assert_eq!(Vector3::new(0, 1, 0).cross(&Vector3::new(0, 0, 1)), Vector3::new(1, 0, 0));
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).cross(&Vector3::new(4.0, 5.0, 6.0)), Vector3::new(-3.0, 6.0, -3.0));
+    assert_eq!(Vector3A::new(1, 0, 0).cross(&Vector3A::new(0, 1, 0)), Vector3A::new(0, 0, 1));
+}
+
+
+#[test]
+fn ray_plane() {
+    let plane = Plane::from_points(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 1.0), Vector3::new(1.0, 1.0, 0.0));
+    assert_eq!(plane, Plane::new(Vector3::new(0.0, 1.0, 0.0), 1.0));
+    assert_eq!(plane.project_point(Vector3::new(3.0, -2.0, 4.0)), Vector3::new(3.0, 1.0, 4.0));
+
+    let ray = Ray3::new(Vector3::new(2.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+    let hit = ray.intersect_plane(&plane).unwrap();
+
+    assert_eq!(hit.distance, 4.0);
+    assert_eq!(hit.point, Vector3::new(2.0, 1.0, 0.0));
+    assert_eq!(hit.normal, Vector3::new(0.0, 1.0, 0.0));
+
+    assert_eq!(Ray3::new(ray.origin, -ray.direction).intersect_plane(&plane), None);
+    assert_eq!(Ray3::new(ray.origin, Vector3::new(1.0, 0.0, 0.0)).intersect_plane(&plane), None);
+
+    let small = Plane::new(Vector3::new(0.0, 1e-4_f32, 0.0), 1e-4);
+    let hit = Ray3::new(Vector3::new(0.0, 3.0, 0.0), Vector3::new(0.0, -1e-3, 0.0)).intersect_plane(&small).unwrap();
+    assert!((hit.point.y - 1.0).abs() < 1e-6);
+}
+
+
+#[test]
+fn ray_sphere() {
+    let sphere = Sphere::new(Vector3::new(0.0, 0.0, 5.0), 2.0);
+    let ray = Ray3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+
+    let hit = ray.intersect_sphere(&sphere).unwrap();
+    assert_eq!(hit.distance, 3.0);
+    assert_eq!(hit.normal, Vector3::new(0.0, 0.0, -1.0));
+
+    let inside = Ray3::new(sphere.center, Vector3::new(1.0, 0.0, 0.0)).intersect_sphere(&sphere).unwrap();
+    assert_eq!(inside.point, Vector3::new(2.0, 0.0, 5.0));
+    assert_eq!(inside.normal, Vector3::new(-1.0, 0.0, 0.0));
+
+    assert_eq!(Ray3::new(ray.origin, Vector3::new(0.0, 1.0, 0.0)).intersect_sphere(&sphere), None);
+    assert_eq!(Ray3::new(ray.origin, -ray.direction).intersect_sphere(&sphere), None);
+
+    assert!(sphere.contains(Vector3::new(0.0, 2.0, 5.0)));
+    assert!(sphere.intersects(&Sphere::new(Vector3::new(0.0, 0.0, 0.0), 3.0)));
+    assert!(!sphere.intersects(&Sphere::new(Vector3::new(0.0, 0.0, 0.0), 2.9)));
+}
+
+
+#[test]
+fn ray_triangle() {
+    let triangle = Triangle3::new(
+        Vector3::new(-1.0, -1.0, 0.0),
+        Vector3::new(1.0, -1.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+
+    let ray = Ray3::new(Vector3::new(0.0, 0.0, 2.0), Vector3::new(0.0, 0.0, -1.0));
+    let hit = ray.intersect_triangle(&triangle).unwrap();
+
+    assert_eq!(hit.distance, 2.0);
+    assert_eq!(hit.point, Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(hit.normal, Vector3::new(0.0, 0.0, 1.0));
+
+    let behind = Ray3::new(Vector3::new(0.0, 0.0, -2.0), Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(behind.intersect_triangle(&triangle).unwrap().normal, Vector3::new(0.0, 0.0, -1.0));
+
+    assert_eq!(Ray3::new(Vector3::new(2.0, 0.0, 2.0), ray.direction).intersect_triangle(&triangle), None);
+    assert_eq!(Ray3::new(ray.origin, Vector3::new(1.0, 0.0, 0.0)).intersect_triangle(&triangle), None);
+
+    let small = Triangle3::new(
+        Vector3::new(-1e-4_f32, -1e-4, 0.0),
+        Vector3::new(1e-4, -1e-4, 0.0),
+        Vector3::new(0.0, 1e-4, 0.0),
+    );
+
+    let hit = Ray3::new(Vector3::new(0.0, 0.0, 1e-3_f32), Vector3::new(0.0, 0.0, -1.0)).intersect_triangle(&small).unwrap();
+    assert_eq!(hit.point, Vector3::new(0.0, 0.0, 0.0));
+    assert_eq!(hit.normal, Vector3::new(0.0, 0.0, 1.0));
+}
+
+
+#[test]
+fn ray_aabb() {
+    let aabb = Aabb3::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+
+    let ray = Ray3::new(Vector3::new(-5.0, 0.5, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    let hit = ray.intersect_aabb(&aabb).unwrap();
+
+    assert_eq!(hit.distance, 4.0);
+    assert_eq!(hit.point, Vector3::new(-1.0, 0.5, 0.0));
+    assert_eq!(hit.normal, Vector3::new(-1.0, 0.0, 0.0));
+
+    let inside = Ray3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0)).intersect_aabb(&aabb).unwrap();
+    assert_eq!(inside.point, Vector3::new(0.0, 0.0, -1.0));
+    assert_eq!(inside.normal, Vector3::new(0.0, 0.0, 1.0));
+
+    assert_eq!(Ray3::new(Vector3::new(-5.0, 2.0, 0.0), ray.direction).intersect_aabb(&aabb), None);
+    assert_eq!(Ray3::new(ray.origin, -ray.direction).intersect_aabb(&aabb), None);
+}
+
+
+#[test]
+fn perp() {
+    assert_eq!(Vector2::new(3, 4).perp(), Vector2::new(-4, 3));
+    assert_eq!(Vector2::new(1.0, 0.0).perp_dot(&Vector2::new(0.0, -1.0)), -1.0);
+}
+
+
+#[test]
+fn line_segment() {
+    let line = Line2::new(Vector2::new(0.0, 1.0), Vector2::new(2.0, 0.0));
+    assert_eq!(line.closest_point(Vector2::new(-5.0, 3.0)), Vector2::new(-5.0, 1.0));
+    assert_eq!(line.intersection(&Line2::new(Vector2::new(0.0, 5.0), Vector2::new(4.0, 0.0))), None);
+
+    let a = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 4.0));
+    let b = Segment2::new(Vector2::new(0.0, 4.0), Vector2::new(4.0, 0.0));
+    let c = Segment2::new(Vector2::new(5.0, 0.0), Vector2::new(5.0, 4.0));
+
+    assert_eq!(a.intersection(&b), Some(Vector2::new(2.0, 2.0)));
+    assert!(!a.intersects(&c));
+    assert_eq!(a.closest_point(Vector2::new(6.0, 6.0)), Vector2::new(4.0, 4.0));
+    assert_eq!(c.distance_to(Vector2::new(2.0, 2.0)), 3.0);
+    assert_eq!(c.length(), 4.0);
+
+    let small = Segment2::new(Vector2::new(0.0, 0.0), Vector2::new(1e-4_f32, 1e-4));
+    let crossing = Segment2::new(Vector2::new(0.0, 1e-4_f32), Vector2::new(1e-4, 0.0));
+    assert!(small.intersects(&crossing));
+
+    let line = Line2::new(Vector2::new(0.0, 0.0), Vector2::new(1e-4_f32, 0.0));
+    let crossing = Line2::new(Vector2::new(3.0, 1.0), Vector2::new(0.0, 1e-4_f32));
+    let point = line.intersection(&crossing).unwrap();
+    assert!((point.x - 3.0).abs() < 1e-6 && point.y == 0.0);
+}
+
+
+#[test]
+fn circle() {
+    let circle = Circle::new(Vector2::new(1.0, 1.0), 1.0);
+
+    assert!(circle.contains(Vector2::new(1.0, 2.0)));
+    assert!(!circle.contains(Vector2::new(2.0, 2.0)));
+    assert!(circle.intersects(&Circle::new(Vector2::new(3.0, 1.0), 1.0)));
+    assert!(!circle.intersects(&Circle::new(Vector2::new(3.0, 1.0), 0.5)));
+    assert_eq!(circle.closest_point(Vector2::new(1.0, 5.0)), Vector2::new(1.0, 2.0));
+    assert_eq!(circle.closest_point(Vector2::new(1.5, 1.0)), Vector2::new(1.5, 1.0));
+}
+
+
+#[test]
+fn triangle2() {
+    let triangle = Triangle2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(0.0, 2.0));
+
+    assert_eq!(triangle.signed_area(), 2.0);
+    assert_eq!(triangle.barycentric(triangle.b), Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(triangle.barycentric(Vector2::new(1.0, 1.0)), Vector3::new(0.0, 0.5, 0.5));
+    assert!(triangle.contains(Vector2::new(0.5, 0.5)));
+    assert!(!triangle.contains(Vector2::new(1.5, 1.5)));
+}
+
+
+#[test]
+fn polygon() {
+    let square = Polygon::new([
+        Vector2::new(0.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(2.0, 2.0),
+        Vector2::new(0.0, 2.0),
+    ]);
+
+    assert_eq!(square.signed_area(), 4.0);
+    assert!(square.contains(Vector2::new(1.0, 1.0)));
+    assert!(!square.contains(Vector2::new(3.0, 1.0)));
+    assert!(!square.contains(Vector2::new(1.0, -1.0)));
+
+    let empty = Polygon::<f32, 0>::new([]);
+    assert!(!empty.contains(Vector2::new(0.0, 0.0)));
+}
+
+
+fn assert_vector3_near(a: Vector3<f64>, b: Vector3<f64>) {
+    assert!((a - b).length() < 1e-9, "{:?} is not near {:?}", a, b);
+}
+
+
+#[test]
+fn affine2() {
+    use core::f64::consts::FRAC_PI_2;
+
+    let rotation = Affine2::from_rotation(FRAC_PI_2);
+    let rotated = rotation.transform_vector(Vector2::new(1.0, 0.0));
+    assert!((rotated - Vector2::new(0.0, 1.0)).length() < 1e-9);
+
+    let affine = Affine2::from_scale_rotation_translation(Vector2::new(2.0, 3.0), 0.5_f64, Vector2::new(4.0, -1.0));
+    let (scale, angle, translation) = affine.to_scale_rotation_translation();
+    assert!((scale - Vector2::new(2.0, 3.0)).length() < 1e-9);
+    assert!((angle - 0.5).abs() < 1e-9);
+    assert_eq!(translation, Vector2::new(4.0, -1.0));
+
+    let point = Vector2::new(1.5, -2.5);
+    let inverse = affine.inverse().unwrap();
+    assert!((inverse.transform_point(affine.transform_point(point)) - point).length() < 1e-9);
+    assert!(((affine * inverse).transform_point(point) - point).length() < 1e-9);
+
+    assert_eq!(Affine2::from_scale(Vector2::new(0.0, 1.0)).inverse(), None);
+    assert_eq!(Affine2::<f32>::default(), Affine2::identity());
+}
+
+
+#[test]
+fn affine3() {
+    use core::f64::consts::FRAC_PI_2;
+
+    assert_vector3_near(Affine3::from_rotation_x(FRAC_PI_2).transform_vector(Vector3::new(0.0, 1.0, 0.0)), Vector3::new(0.0, 0.0, 1.0));
+    assert_vector3_near(Affine3::from_rotation_y(FRAC_PI_2).transform_vector(Vector3::new(0.0, 0.0, 1.0)), Vector3::new(1.0, 0.0, 0.0));
+    assert_vector3_near(Affine3::from_rotation_z(FRAC_PI_2).transform_vector(Vector3::new(1.0, 0.0, 0.0)), Vector3::new(0.0, 1.0, 0.0));
+
+    let axis = Vector3::new(1.0, 2.0, 3.0).normalized();
+    let from_axis_angle = Affine3::from_axis_angle(axis, 0.75);
+    assert_vector3_near(from_axis_angle.transform_vector(axis), axis);
+    assert_vector3_near(Affine3::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 0.3).x_axis, Affine3::from_rotation_z(0.3).x_axis);
+
+    let rotation = Vector3::new(0.3, -0.6, 1.2);
+    let affine = Affine3::from_scale_rotation_translation(Vector3::new(-2.0, 3.0, 0.5), rotation, Vector3::new(1.0, 2.0, 3.0));
+    let (scale, decomposed, translation) = affine.to_scale_rotation_translation();
+    assert_vector3_near(scale, Vector3::new(-2.0, 3.0, 0.5));
+    assert_vector3_near(decomposed, rotation);
+    assert_eq!(translation, Vector3::new(1.0, 2.0, 3.0));
+
+    let gimbal = Affine3::from_rotation(Vector3::new(0.4, FRAC_PI_2, 0.0));
+    let (_, decomposed, _) = gimbal.to_scale_rotation_translation();
+    assert_vector3_near(Affine3::from_rotation(decomposed).transform_vector(Vector3::new(1.0, 2.0, 3.0)), gimbal.transform_vector(Vector3::new(1.0, 2.0, 3.0)));
+
+    let point = Vector3::new(-1.0, 0.5, 4.0);
+    let inverse = affine.inverse().unwrap();
+    assert_vector3_near(inverse.transform_point(affine.transform_point(point)), point);
+    assert_vector3_near((inverse * affine).transform_point(point), point);
+
+    assert_eq!(Affine3::from_scale(Vector3::new(1.0, 0.0, 1.0)).inverse(), None);
+}
+
+
+fn assert_matrix4_near(a: Matrix4<f64>, b: Matrix4<f64>) {
+    for (a, b) in a.to_cols_array().iter().flatten().zip(b.to_cols_array().iter().flatten()) {
+        assert!((a - b).abs() < 1e-9, "{:?} is not near {:?}", a, b);
+    }
+}
+
+
+#[test]
+fn matrix4_inverse() {
+    let affine = Affine3::from_scale_rotation_translation(Vector3::new(1.0_f64, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3), Vector3::new(4.0, 5.0, 6.0));
+    let matrix = Matrix4::from(affine);
+
+    assert!((matrix.determinant() - 6.0).abs() < 1e-9);
+    assert_matrix4_near(matrix * matrix.inverse().unwrap(), Matrix4::identity());
+    assert_matrix4_near(matrix.inverse().unwrap(), Matrix4::from(affine.inverse().unwrap()));
+    assert_matrix4_near(matrix.transpose().transpose(), matrix);
+
+    let projection = Matrix4::perspective_rh_gl(1.0, 1.5, 0.1, 50.0);
+    assert_matrix4_near(projection.inverse().unwrap() * projection, Matrix4::identity());
+
+    assert_eq!(Matrix4::from(Affine3::from_scale(Vector3::new(0.0, 1.0, 1.0))).inverse(), None);
+}
+
+
+#[test]
+fn perspective() {
+    let (near, far) = (0.5, 20.0);
+    let fov = core::f64::consts::FRAC_PI_2;
+
+    let cases = [
+        (Matrix4::perspective_rh(fov, 2.0, near, far), -1.0, 0.0),
+        (Matrix4::perspective_lh(fov, 2.0, near, far), 1.0, 0.0),
+        (Matrix4::perspective_rh_gl(fov, 2.0, near, far), -1.0, -1.0),
+        (Matrix4::perspective_lh_gl(fov, 2.0, near, far), 1.0, -1.0),
+    ];
+
+    for (projection, forward, near_depth) in cases {
+        assert_vector3_near(projection.project_point(Vector3::new(0.0, 0.0, near * forward)), Vector3::new(0.0, 0.0, near_depth));
+        assert_vector3_near(projection.project_point(Vector3::new(0.0, 0.0, far * forward)), Vector3::new(0.0, 0.0, 1.0));
+        assert_vector3_near(projection.project_point(Vector3::new(4.0, 2.0, 2.0 * forward)), Vector3::new(1.0, 1.0, projection.project_point(Vector3::new(0.0, 0.0, 2.0 * forward)).z));
+    }
+}
+
+
+#[test]
+fn orthographic() {
+    let cases = [
+        (Matrix4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0), -1.0, 0.0),
+        (Matrix4::orthographic_lh(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0), 1.0, 0.0),
+        (Matrix4::orthographic_rh_gl(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0), -1.0, -1.0),
+        (Matrix4::orthographic_lh_gl(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0), 1.0, -1.0),
+    ];
+
+    for (projection, forward, near_depth) in cases {
+        assert_vector3_near(projection.project_point(Vector3::new(-2.0, -1.0, forward)), Vector3::new(-1.0, -1.0, near_depth));
+        assert_vector3_near(projection.project_point(Vector3::new(2.0, 1.0, 11.0 * forward)), Vector3::new(1.0, 1.0, 1.0));
+    }
+}
+
+
+#[test]
+fn look_at() {
+    let eye = Vector3::new(1.0, 2.0, 3.0);
+    let target = Vector3::new(1.0, 2.0, -3.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+
+    let view = Matrix4::look_at_rh(eye, target, up);
+    assert_vector3_near(view.project_point(eye), Vector3::new(0.0, 0.0, 0.0));
+    assert_vector3_near(view.project_point(target), Vector3::new(0.0, 0.0, -6.0));
+    assert_vector3_near(view.project_point(Vector3::new(2.0, 3.0, 3.0)), Vector3::new(1.0, 1.0, 0.0));
+
+    let view = Matrix4::look_at_lh(eye, target, up);
+    assert_vector3_near(view.project_point(target), Vector3::new(0.0, 0.0, 6.0));
+    assert_vector3_near(view.project_point(Vector3::new(2.0, 3.0, 3.0)), Vector3::new(-1.0, 1.0, 0.0));
+}
+
+
+#[test]
+fn project_unproject() {
+    let view = Matrix4::look_at_rh(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    let projection = Matrix4::perspective_rh(1.2, 16.0 / 9.0, 0.1, 100.0);
+    let view_projection = projection * view;
+    let viewport = Vector4::new(0.0, 0.0, 1920.0, 1080.0);
+
+    assert_vector3_near(view_projection.project(Vector3::new(0.0, 0.0, 0.0), viewport).round(), Vector3::new(960.0, 540.0, 1.0));
+
+    let point = Vector3::new(0.5, -1.0, 2.0);
+    let screen = view_projection.project(point, viewport);
+    assert_vector3_near(view_projection.unproject(screen, viewport).unwrap(), point);
+}
+
+
+fn assert_fixed_near<const FRAC: u32>(a: Fixed<i32, FRAC>, b: f64, tolerance: f64) {
+    assert!((a.to_f64() - b).abs() <= tolerance, "{:?} != {:?}", a, b);
+}
+
+
+#[test]
+fn fixed_scalar() {
+    use crate::macros::floating::FloatingPoint;
+    use num_traits::float::FloatCore;
+
+    let half = Q16_16::from_f64(0.5);
+    let two = Q16_16::from_int(2);
+
+    assert_eq!(half + half, Q16_16::ONE);
+    assert_eq!(two * half, Q16_16::ONE);
+    assert_eq!(Q16_16::ONE / two, half);
+    assert_eq!(Q16_16::ONE / Q16_16::ZERO, Q16_16::MAX);
+    assert_eq!(Q16_16::MAX + Q16_16::ONE, Q16_16::MAX);
+    assert_eq!(Q16_16::from_f64(-1.5).floor(), Q16_16::from_int(-2));
+    assert_eq!(Q16_16::from_f64(-1.5).round(), Q16_16::from_int(-2));
+    assert_eq!(Q16_16::from_f64(-1.5).trunc(), Q16_16::from_int(-1));
+    assert_eq!(num_traits::cast::<_, Q16_16>(3), Some(Q16_16::from_int(3)));
+    assert_eq!(num_traits::cast::<_, Q16_16>(1e9), None);
+    assert_eq!(num_traits::cast::<_, Q16_16>(-32768), Some(Q16_16::from_int(-32768)));
+    assert_eq!(num_traits::cast::<_, Q16_16>(32768), None);
+    assert_eq!(num_traits::cast::<_, Q8_8>(127.99609375), Some(Q8_8::MAX));
+
+    assert_eq!(two.sqrt().to_bits(), 92681);
+    assert_fixed_near(Q16_16::PI.sin(), 0.0, 1e-4);
+    assert_fixed_near(Q16_16::from_f64(1.0).cos(), 1.0_f64.cos(), 1e-4);
+    assert_fixed_near(Q16_16::from_f64(-2.5).sin(), (-2.5_f64).sin(), 1e-4);
+    assert_fixed_near(Q16_16::from_int(-1).atan2(Q16_16::from_int(-1)), (-1.0_f64).atan2(-1.0), 1e-4);
+    assert_fixed_near(half.acos(), 0.5_f64.acos(), 1e-3);
+    assert_fixed_near(Q16_16::from_f64(-1.25).exp2(), (-1.25_f64).exp2(), 1e-4);
+    assert_fixed_near(Q16_16::from_int(10).exp2(), 1024.0, 0.0);
+}
+
+
+#[test]
+fn fixed_vectors() {
+    let vec3 = Vector3::new(Q16_16::from_int(2), Q16_16::from_int(-3), Q16_16::from_int(6));
+
+    assert_eq!(vec3.length(), Q16_16::from_int(7));
+    assert_eq!(vec3.dot(&vec3), Q16_16::from_int(49));
+    assert!(vec3.normalized().is_normalized());
+    assert_fixed_near(vec3.normalized().y, -3.0 / 7.0, 1e-4);
+    assert_eq!(Vector3::<Q16_16>::default().try_normalize(), None);
+
+    let lerped = vec3.lerp(Vector3::from_value(Q16_16::ZERO), Q16_16::from_f64(0.5));
+    assert_eq!(lerped, Vector3::new(Q16_16::ONE, Q16_16::from_f64(-1.5), Q16_16::from_int(3)));
+
+    let precise = Vector3::new(Q32_32::from_f64(0.1), Q32_32::from_f64(0.2), Q32_32::from_f64(0.3));
+    assert!((precise.length().to_f64() - 0.14_f64.sqrt()).abs() < 1e-8);
+
+    // NOTE: The squared lengths of these vectors saturate at `MAX`.
+    let long = Vector3::new(Q16_16::from_int(200), Q16_16::ZERO, Q16_16::ZERO);
+    assert_eq!(long.length(), Q16_16::from_int(200));
+    assert_eq!(long.normalized(), Vector3::new(Q16_16::ONE, Q16_16::ZERO, Q16_16::ZERO));
+
+    let diagonal = Vector2::new(Q16_16::from_int(150), Q16_16::from_int(150));
+    assert_fixed_near(diagonal.length(), 150.0 * 2.0_f64.sqrt(), 1e-2);
+    assert!(diagonal.normalized().is_normalized());
+    assert!(diagonal.try_normalize().is_some_and(|normalized| normalized.is_normalized()));
+    assert_fixed_near(diagonal.clamp_length_max(Q16_16::from_int(10)).x, 10.0 / 2.0_f64.sqrt(), 1e-3);
+
+    let small = Vector2::new(Q8_8::from_int(20), Q8_8::from_int(-20));
+    assert!(small.normalized().is_normalized());
+    assert!((small.normalized().y.to_f64() + 0.5_f64.sqrt()).abs() < 1e-2);
+}
+
+
+#[cfg(feature = "half")]
+#[test]
+fn half_vectors() {
+    use half::{bf16, f16};
+
+    let vec3 = Vector3::new(f16::from_f32(2.0), f16::from_f32(-3.0), f16::from_f32(6.0));
+    assert_eq!(vec3.length(), f16::from_f32(7.0));
+    assert!(vec3.normalized().is_normalized());
+
+    let vec2 = Vector2::new(bf16::from_f32(3.0), bf16::from_f32(4.0));
+    assert_eq!(vec2.length(), bf16::from_f32(5.0));
+    assert_eq!(vec2.normalized(), Vector2::new(bf16::from_f32(0.6), bf16::from_f32(0.8)));
+}
+
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", Vector2::new(1, 2)), "(1, 2)");
+    assert_eq!(format!("{:.2}", Vector3::new(1.0, 2.5, -3.0)), "(1.00, 2.50, -3.00)");
+    assert_eq!(format!("{:>3}", Vector4::new(1, 2, 3, 4)), "(  1,   2,   3,   4)");
+}
+
+
+#[test]
+fn from_str() {
+    let expected = Vector2::new(1, -2);
+
+    assert_eq!("(1, -2)".parse(), Ok(expected));
+    assert_eq!("1,-2".parse(), Ok(expected));
+    assert_eq!("[1 -2]".parse(), Ok(expected));
+    assert_eq!(" ( 1 , -2 , ) ".parse(), Ok(expected));
+    assert_eq!(format!("{:?}", expected).parse(), Ok(expected));
+    assert_eq!(format!("{:#?}", expected).parse(), Ok(expected));
+    assert_eq!(expected.to_string().parse(), Ok(expected));
+
+    let vec3 = Vector3::new(0.5, -1.25, 1e10);
+    assert_eq!(vec3.to_string().parse(), Ok(vec3));
+    assert_eq!(format!("{:?}", vec3).parse(), Ok(vec3));
+
+    assert_eq!("(1, 2".parse::<Vector2<i32>>(), Err(ParseVectorError::InvalidFormat));
+    assert_eq!("Vector2 { y: 1, x: 2 }".parse::<Vector2<i32>>(), Err(ParseVectorError::InvalidFormat));
+    assert_eq!("Vector3 { x: 1, y: 2 }".parse::<Vector2<i32>>(), Err(ParseVectorError::InvalidFormat));
+    assert_eq!("()".parse::<Vector2<i32>>(), Err(ParseVectorError::ComponentCount { expected: 2, found: 0 }));
+    assert_eq!("1".parse::<Vector2<i32>>(), Err(ParseVectorError::ComponentCount { expected: 2, found: 1 }));
+
+    let error = "(1, 2, x)".parse::<Vector3<u8>>().unwrap_err();
+    assert_eq!(error, ParseVectorError::InvalidComponent { field: "z", error: "x".parse::<u8>().unwrap_err() });
+    assert_eq!(error.to_string(), "invalid value for component `z`: invalid digit found in string");
+}
+
+
+#[test]
+fn bytes() {
+    let vec3 = Vector3::new(0x0102_u16, 0x0304, 0x0506);
+
+    assert_eq!(vec3.to_le_bytes(), [0x02, 0x01, 0x04, 0x03, 0x06, 0x05]);
+    assert_eq!(vec3.to_be_bytes(), [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+    assert_eq!(Vector3::<u16>::from_le_bytes(vec3.to_le_bytes()), vec3);
+    assert_eq!(Vector3::<u16>::from_be_bytes(vec3.to_be_bytes()), vec3);
+    assert_eq!(Vector3::<u16>::from_ne_bytes(vec3.to_ne_bytes()), vec3);
+
+    let vec4 = Vector4A::new(1.5_f64, -2.0, f64::MAX, f64::MIN_POSITIVE);
+    assert_eq!(Vector4A::<f64>::from_be_bytes(vec4.to_be_bytes()), vec4);
+
+    let mut buffer = [0_u8; 16];
+    assert_eq!(Vector2::new(-1_i32, 7).write_to(&mut buffer[4..]), Ok(8));
+    assert_eq!(Vector2::<i32>::read_from(&buffer[4..]), Ok(Vector2::new(-1, 7)));
+    assert_eq!(Vector3::new(1_i32, 2, 3).write_to(&mut buffer[8..]), Err(BufferLengthError { required: 12, found: 8 }));
+    assert_eq!(Vector4::<u64>::read_from(&buffer), Err(BufferLengthError { required: 32, found: 16 }));
+}
+
+
+#[test]
+fn radix_formatting() {
+    let vec3 = Vector3::new(255_u8, 16, 7);
+
+    assert_eq!(format!("{:x}", vec3), "(ff, 10, 7)");
+    assert_eq!(format!("{:#X}", vec3), "(0xFF, 0x10, 0x7)");
+    assert_eq!(format!("{:08b}", Vector2::new(5_u8, 255)), "(00000101, 11111111)");
+    assert_eq!(format!("{:o}", Vector2::new(8, 64)), "(10, 100)");
+    assert_eq!(format!("{:.1e}", Vector2::new(1500.0, 0.25)), "(1.5e3, 2.5e-1)");
+    assert_eq!(format!("{:E}", Vector2::new(1500.0, 0.25)), "(1.5E3, 2.5E-1)");
+}
+
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_conversions() {
+    let vec2 = Vector2::new(1, 2);
+    let vec3 = Vector3::new(1.0, 2.0, 3.0);
+    let vec4 = Vector4::new(1_u8, 2, 3, 4);
+
+    assert_eq!(mint::Vector2::from(vec2), mint::Vector2 { x: 1, y: 2 });
+    assert_eq!(Vector2::from(mint::Vector2::from(vec2)), vec2);
+    assert_eq!(Vector3::from(mint::Vector3::from(vec3)), vec3);
+    assert_eq!(Vector4::from(mint::Vector4::from(vec4)), vec4);
+}
+
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_conversions() {
+    let vec2 = Vector2::new(1.0_f32, 2.0);
+    let vec3 = Vector3::new(1.0_f64, 2.0, 3.0);
+    let vec4 = Vector4::new(1_i32, -2, 3, -4);
+    let vec3a = Vector3A::new(1.0_f32, 2.0, 3.0);
+
+    assert_eq!(glam::Vec2::from(vec2), glam::Vec2::new(1.0, 2.0));
+    assert_eq!(Vector2::from(glam::Vec2::from(vec2)), vec2);
+    assert_eq!(Vector3::from(glam::DVec3::from(vec3)), vec3);
+    assert_eq!(Vector4::from(glam::IVec4::from(vec4)), vec4);
+    assert_eq!(Vector3A::from(glam::Vec3A::from(vec3a)), vec3a);
+}
+
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_conversions() {
+    let vec2 = Vector2::new(1.0, 2.0);
+    let vec3 = Vector3::new(1, 2, 3);
+    let vec4 = Vector4::new(1.0_f32, 2.0, 3.0, 4.0);
+
+    assert_eq!(nalgebra::Vector2::from(vec2), nalgebra::Vector2::new(1.0, 2.0));
+    assert_eq!(Vector2::from(nalgebra::Vector2::from(vec2)), vec2);
+    assert_eq!(Vector3::from(nalgebra::Vector3::from(vec3)), vec3);
+    assert_eq!(Vector4::from(nalgebra::Vector4::from(vec4)), vec4);
+}
+
+
+#[cfg(feature = "cgmath")]
+#[test]
+fn cgmath_conversions() {
+    let vec2 = Vector2::new(1.0, 2.0);
+    let vec3 = Vector3::new(1, 2, 3);
+    let vec4 = Vector4::new(1.0_f32, 2.0, 3.0, 4.0);
+
+    assert_eq!(cgmath::Vector2::from(vec2), cgmath::Vector2::new(1.0, 2.0));
+    assert_eq!(Vector2::from(cgmath::Vector2::from(vec2)), vec2);
+    assert_eq!(Vector3::from(cgmath::Vector3::from(vec3)), vec3);
+    assert_eq!(Vector4::from(cgmath::Vector4::from(vec4)), vec4);
+}
+
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_vectors() {
+    use rand::distributions::{Distribution, Uniform};
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    let mut rng = SmallRng::seed_from_u64(0x5EED);
+
+    let standard: Vector3<f64> = rng.gen();
+    assert!(standard.x >= 0.0 && standard.x < 1.0);
+
+    let uniform = Uniform::new(Vector2::new(-5, 10), Vector2::new(5, 20));
+    let inclusive = Uniform::new_inclusive(Vector4A::from_value(1.0_f32), Vector4A::from_value(2.0));
+
+    for _ in 0..256 {
+        let vec2 = uniform.sample(&mut rng);
+        assert!((-5..5).contains(&vec2.x) && (10..20).contains(&vec2.y));
+
+        let vec4 = inclusive.sample(&mut rng);
+        assert!(vec4.to_array().iter().all(|field| (1.0..=2.0).contains(field)));
+
+        assert!(rng.sample::<Vector2<f64>, _>(UnitCircle).is_normalized());
+        assert!(rng.sample::<Vector3<f32>, _>(UnitSphere).is_normalized());
+        assert!(rng.sample::<Vector2<f64>, _>(InsideUnitDisk).length_squared() <= 1.0);
+        assert!(rng.sample::<Vector3<f64>, _>(InsideUnitSphere).length_squared() <= 1.0);
+    }
+}
+
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_vectors() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let bytes = [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0];
+    let vec3 = Vector3::<u32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+    assert_eq!(vec3, Vector3::new(1, 2, 3));
+    assert_eq!(Vector4::<u16>::size_hint(0), (8, Some(8)));
+}
+
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_properties {
+    use super::*;
+    use quickcheck::{quickcheck, Arbitrary};
+
+    quickcheck! {
+        fn add_sub_round_trip(a: Vector3<i16>, b: Vector3<i16>) -> bool {
+            let (a, b) = (a.map(i32::from), b.map(i32::from));
+            a + b - b == a
+        }
+
+        fn shrinks_towards_zero(vec2: Vector2<i32>) -> bool {
+            vec2.shrink().all(|shrunk| shrunk.x.unsigned_abs() <= vec2.x.unsigned_abs() && shrunk.y.unsigned_abs() <= vec2.y.unsigned_abs())
+        }
+    }
+}
+
+
+#[cfg(feature = "proptest")]
+mod proptest_properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn normalized_length(vec3 in strategy::finite_vector3(-1e6..=1e6_f64)) {
+            prop_assume!(vec3.length() > 1e-6);
+            prop_assert!((vec3.normalized().length() - 1.0).abs() < 1e-9);
+        }
+
+        #[test]
+        fn normalized_strategy(vec4 in strategy::normalized_vector4::<f32>()) {
+            prop_assert!(vec4.is_normalized());
+        }
+
+        #[test]
+        fn mul_div_round_trip(vec2 in any::<Vector2<i16>>(), scale in 1_i32..100) {
+            let vec2 = vec2.map(i32::from);
+            prop_assert_eq!(vec2 * scale / scale, vec2);
+        }
+    }
+}
+
+
+#[test]
+fn lexicographic_ordering() {
+    let mut cells = [Vector2::new(2, 1), Vector2::new(1, 3), Vector2::new(1, -2), Vector2::new(0, 9)];
+    cells.sort();
+
+    assert_eq!(cells, [Vector2::new(0, 9), Vector2::new(1, -2), Vector2::new(1, 3), Vector2::new(2, 1)]);
+    assert!(Vector3::new(1.0, 2.0, 3.0) < Vector3::new(1.0, 2.0, 4.0));
+    assert_eq!(Vector2::new(f64::NAN, 0.0).partial_cmp(&Vector2::new(1.0, 0.0)), None);
+    assert_eq!(Vector2::new(1, 5).max(Vector2::new(2, 0)), Vector2::new(2, 0));
+}
+
+
+#[test]
+fn total_ord() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let vertices = [
+        Vector3::new(0.0_f32, 1.0, 2.0),
+        Vector3::new(0.0, 1.0, 2.0),
+        Vector3::new(-0.0, 1.0, 2.0),
+        Vector3::new(f32::NAN, 0.0, 0.0),
+        Vector3::new(f32::NAN, 0.0, 0.0),
+    ];
+
+    let hashed: HashSet<_> = vertices.iter().copied().map(TotalOrd).collect();
+    let ordered: BTreeSet<_> = vertices.iter().copied().map(TotalOrd).collect();
+
+    assert_eq!(hashed.len(), 3);
+    assert_eq!(ordered.len(), 3);
+    assert_eq!(ordered.first().unwrap().0.x.to_bits(), (-0.0_f32).to_bits());
+
+    let vec2 = Vector2::new(1.5_f64, -2.0);
+    assert_eq!(Vector2::<f64>::from_bits(vec2.to_bits()), vec2);
+    assert_eq!(vec2.total_cmp(&Vector2::new(1.5, -1.0)), core::cmp::Ordering::Less);
+}
+
+
+#[test]
+fn length_ordering() {
+    use core::cmp::Ordering;
+
+    let mut vectors = [Vector3::new(0.0, 0.0, 3.0), Vector3::new(1.0, 1.0, 1.0), Vector3::new(-2.0, 0.0, 0.0)];
+    Vector3::sort_by_length(&mut vectors);
+
+    assert_eq!(vectors, [Vector3::new(1.0, 1.0, 1.0), Vector3::new(-2.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 3.0)]);
+    assert_eq!(Vector2::new(f32::NAN, 0.0).cmp_by_length(&Vector2::new(1.0, 0.0)), None);
+    assert_eq!(Vector2::new(0, 5).cmp_by_length(&Vector2::new(-3, 4)), Some(Ordering::Equal));
+
+    // NOTE: Squaring any of these fields within their own type would overflow.
+    assert_eq!(Vector2::new(200_u8, 0).cmp_by_length(&Vector2::new(1, 0)), Some(Ordering::Greater));
+    assert_eq!(Vector2::new(50_000_i32, 0).cmp_by_length(&Vector2::new(1, 0)), Some(Ordering::Greater));
+    assert_eq!(Vector4::from_value(i64::MIN).cmp_by_length(&Vector4::from_value(i64::MAX)), Some(Ordering::Greater));
+    assert_eq!(Vector4::<u64>::from_value(u64::MAX).cmp_by_length(&Vector4::new(u64::MAX, u64::MAX, 0, 0)), Some(Ordering::Greater));
+    assert_eq!(Vector3::<u64>::from_value(u64::MAX).cmp_by_length(&Vector3::new(u64::MAX, u64::MAX, 0)), Some(Ordering::Greater));
+    assert_eq!(Vector3::new(1e200, 0.0, 0.0).cmp_by_length(&Vector3::new(0.0, 2e199, 1e199)), Some(Ordering::Greater));
+    assert_eq!(Vector2::new(1e-200, 0.0).cmp_by_length(&Vector2::new(0.0, 2e-200)), Some(Ordering::Less));
+    assert_eq!(Vector2::new(f64::INFINITY, 0.0).cmp_by_length(&Vector2::new(f64::MAX, f64::MAX)), Some(Ordering::Greater));
+
+    let (big, small) = (Q16_16::from_int(200), Q16_16::from_int(150));
+    assert_eq!(Vector2::new(big, Q16_16::ZERO).cmp_by_length(&Vector2::new(small, small)), Some(Ordering::Less));
+
+    let mut vectors: [Vector2<f32>; 40] = core::array::from_fn(|index| match index % 4 {
+        0 => Vector2::new(f32::NAN, index as f32),
+        _ => Vector2::new(index as f32, 0.0),
+    });
+
+    Vector2::sort_by_length(&mut vectors);
+
+    assert!(vectors[..30].windows(2).all(|pair| pair[0].x < pair[1].x));
+    assert!(vectors[30..].iter().all(|vector| vector.x.is_nan()));
+}
+
+
+#[test]
+fn z_order() {
+    assert_eq!(Vector2::new(-1_i8, 0).to_morton(), 0x9555);
+    assert_eq!(Vector2::<i32>::from_morton(Vector2::new(i32::MIN, -5).to_morton()), Vector2::new(i32::MIN, -5));
+    assert_eq!(Vector2::new(u64::MAX, u64::MAX).to_morton(), u128::MAX);
+    let vec3 = Vector3::new(-(1_i32 << 20), 5, (1 << 20) - 1);
+    assert_eq!(Vector3::<i32>::from_morton(vec3.to_morton()), vec3);
+
+    for x in i8::MIN..=i8::MAX {
+        let vec2 = Vector2::new(x, x.wrapping_mul(3));
+
+        assert_eq!(Vector2::<i8>::from_morton(vec2.to_morton()), vec2);
+        assert_eq!(Vector2::<i8>::from_hilbert(vec2.to_hilbert()), vec2);
+    }
+
+    let vec2 = Vector2::new(i64::MIN, 0x1234_5678_9ABC_DEF0);
+    assert_eq!(Vector2::<i64>::from_hilbert(vec2.to_hilbert()), vec2);
+
+    let mut cells = [Vector2::new(1_i32, 1), Vector2::new(-1, -1), Vector2::new(0, 1), Vector2::new(1, 0), Vector2::new(0, 0)];
+    Vector2::<i32>::sort_by_z_order(&mut cells);
+
+    assert_eq!(cells, [Vector2::new(-1, -1), Vector2::new(0, 0), Vector2::new(1, 0), Vector2::new(0, 1), Vector2::new(1, 1)]);
+
+    // NOTE: `to_morton` only keeps 21 bits of each field here, while sorting uses all of them.
+    let mut voxels = [Vector3::new(1_u32 << 21, 0, 0), Vector3::new(1, 0, 0)];
+    Vector3::<u32>::sort_by_z_order(&mut voxels);
+
+    assert_eq!(voxels, [Vector3::new(1, 0, 0), Vector3::new(1 << 21, 0, 0)]);
+}
+
+
+#[test]
+fn morton_codes() {
+    assert_eq!(Vector2::new(0b11_u16, 0b00).to_morton(), 0b0101);
+    assert_eq!(Vector3::new(0b1_u8, 0b0, 0b1).to_morton(), 0b101);
+    assert_eq!(Vector3::new(u32::MAX, 0, 0).to_morton(), 0x1249249249249249);
+    assert_eq!(Vector3::<u32>::from_morton(u64::MAX), Vector3::new(0x1FFFFF, 0x1FFFFF, 0x1FFFFF));
+    assert_eq!(Vector3::<u32>::from_morton(u64::MAX).to_morton(), u64::MAX >> 1);
+
+    for x in 0..=u8::MAX {
+        for y in 0..=u8::MAX {
+            let vec2 = Vector2::new(x, y);
+            assert_eq!(Vector2::<u8>::from_morton(vec2.to_morton()), vec2);
+        }
+    }
+
+    for code in 0..(1 << 15) {
+        assert_eq!(Vector3::<u16>::from_morton(code).to_morton(), code);
+        assert_eq!(Vector3::<u32>::from_morton(code << 40).to_morton(), code << 40);
+    }
+
+    let vec2 = Vector2::new(u32::MAX, 0x1234_5678);
+    assert_eq!(Vector2::<u32>::from_morton(vec2.to_morton()), vec2);
+}
+
+
+#[test]
+fn hilbert_indices() {
+    assert_eq!(Vector2::new(0_u8, 0).to_hilbert(), 0);
+    assert_eq!(Vector3::new(0_u16, 0, 0).to_hilbert(), 0);
+
+    for index in 0..u16::MAX {
+        let (vec2, next) = (Vector2::<u8>::from_hilbert(index), Vector2::<u8>::from_hilbert(index + 1));
+
+        assert_eq!(vec2.to_hilbert(), index);
+        assert_eq!(vec2.x.abs_diff(next.x) + vec2.y.abs_diff(next.y), 1);
+    }
+
+    for index in 0..(1 << 15) {
+        let (vec3, next) = (Vector3::<u8>::from_hilbert(index), Vector3::<u8>::from_hilbert(index + 1));
+
+        assert_eq!(vec3.to_hilbert(), index);
+        assert_eq!(vec3.x.abs_diff(next.x) + vec3.y.abs_diff(next.y) + vec3.z.abs_diff(next.z), 1);
+    }
+
+    for vec3 in [Vector3::new(0x1FFFFF_u32, 0, 7), Vector3::new(123_456, 654_321, 1)] {
+        assert_eq!(Vector3::<u32>::from_hilbert(vec3.to_hilbert()), vec3);
+    }
+
+    let vec2 = Vector2::new(u32::MAX, 0x1234_5678);
+    assert_eq!(Vector2::<u32>::from_hilbert(vec2.to_hilbert()), vec2);
+}
+
+
+#[test]
+fn grid_neighbors() {
+    let neighbors: std::vec::Vec<_> = Vector2::new(0_i32, 0).von_neumann_neighbors().collect();
+    assert_eq!(neighbors, [Vector2::new(-1, 0), Vector2::new(1, 0), Vector2::new(0, -1), Vector2::new(0, 1)]);
+
+    let neighbors: std::vec::Vec<_> = Vector2::new(5_i32, 5).moore_neighbors().collect();
+    assert_eq!(neighbors.len(), 8);
+    assert_eq!(neighbors[0], Vector2::new(4, 4));
+    assert_eq!(neighbors[7], Vector2::new(6, 6));
+    assert!(!neighbors.contains(&Vector2::new(5, 5)));
+
+    assert_eq!(Vector2::new(0_u8, 0).von_neumann_neighbors().count(), 2);
+    assert_eq!(Vector2::new(0_u8, u8::MAX).moore_neighbors().count(), 3);
+
+    assert_eq!(Vector3::new(1_i64, 1, 1).von_neumann_neighbors().count(), 6);
+    assert_eq!(Vector3::new(1_i64, 1, 1).moore_neighbors().count(), 26);
+    assert_eq!(Vector3::new(0_u32, 0, 0).moore_neighbors().count(), 7);
+
+    for neighbor in Vector3::new(1_i64, 1, 1).von_neumann_neighbors() {
+        assert_eq!(neighbor.x.abs_diff(1) + neighbor.y.abs_diff(1) + neighbor.z.abs_diff(1), 1);
+    }
+}
+
+
+#[test]
+fn grid_indices() {
+    for index in 0..64 {
+        assert_eq!(Vector2::<i32>::from_index(index, 8).and_then(|vec2| vec2.to_index(8)), Some(index));
+        assert_eq!(Vector3::<u16>::from_index(index, 4, 2).and_then(|vec3| vec3.to_index(4, 2)), Some(index));
+    }
+
+    assert_eq!(Vector3::new(1_u8, 2, 3).to_index(4, 5), Some(1 + 2 * 4 + 3 * 4 * 5));
+    assert_eq!(Vector2::new(-1_i32, 0).to_index(8), None);
+    assert_eq!(Vector3::new(0_i32, 5, 0).to_index(8, 5), None);
+    assert_eq!(Vector2::<i32>::from_index(1, 0), None);
+    assert_eq!(Vector2::<u8>::from_index(4096, 2), None);
+}
+
+
+#[test]
+fn vector_ranges() {
+    let range = VectorRange::new(Vector2::new(-1_i32, 2), Vector2::new(2, 4));
+    assert_eq!(range.size_hint(), (6, Some(6)));
+
+    let points: std::vec::Vec<_> = range.collect();
+    assert_eq!(points, [
+        Vector2::new(-1, 2), Vector2::new(0, 2), Vector2::new(1, 2),
+        Vector2::new(-1, 3), Vector2::new(0, 3), Vector2::new(1, 3),
+    ]);
+
+    let mut range = VectorRange::new(Vector3::new(0_u8, 0, 0), Vector3::new(3, 4, 5));
+    for index in 0..60 {
+        assert_eq!(range.size_hint(), (60 - index, Some(60 - index)));
+        assert_eq!(range.next().and_then(|vec3| vec3.to_index(3, 4)), Some(index));
+    }
+
+    assert_eq!(range.next(), None);
+    assert_eq!(range.next(), None);
+
+    assert_eq!(VectorRange::new(Vector2::new(0, 0), Vector2::new(3, 0)).count(), 0);
+    assert_eq!(VectorRange::new(Vector2::new(0, 5), Vector2::new(3, 2)).size_hint(), (0, Some(0)));
+    assert_eq!(VectorRange::new(Vector2::new(i64::MIN, 0), Vector2::new(i64::MAX, 2)).size_hint(), (0, None));
 }
\ No newline at end of file
@@ -0,0 +1,121 @@
+use crate::{Vector2, Vector3, Vector4, Vector3A, Vector4A};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+
+/// Wrapper giving floating-point vectors a total order, along with [`Eq`] and [`Hash`] implementations
+/// based on the bits of their fields, so they can be used as keys within `HashMap`s and `BTreeMap`s.
+/// 
+/// Fields are compared with [`f32::total_cmp`] and [`f64::total_cmp`] in declaration order, meaning `-0.0`
+/// is ordered before, and isn't equal to, `0.0`, and `NaN`s are equal to themselves when their bits match.
+/// 
+/// # Example
+/// 
+/// ```
+/// use fixed_vectors::{TotalOrd, Vector2};
+/// 
+/// let mut vertices = [
+///     TotalOrd(Vector2::new(1.0, 2.0)),
+///     TotalOrd(Vector2::new(f32::NAN, 0.0)),
+///     TotalOrd(Vector2::new(-1.0, 5.0)),
+///     TotalOrd(Vector2::new(1.0, 2.0)),
+/// ];
+/// 
+/// vertices.sort();
+/// 
+/// assert_eq!(vertices[0].0, Vector2::new(-1.0, 5.0));
+/// assert_eq!(vertices[1], vertices[2]);
+/// assert!(vertices[3].0.x.is_nan());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct TotalOrd<V>(pub V);
+
+
+macro_rules! impl_total_ord {
+    ( $struct: ident $fields: tt, $($type: ty: $bits: ty), + ) => {
+        $( impl_total_ord!( @type $struct $fields, $type, $bits ); ) +
+    };
+
+    ( @type $struct: ident { $($field: ident), + }, $type: ty, $bits: ty ) => {
+        impl $struct<$type> {
+            /// Returns the raw bits of each field within the vector.
+            #[inline]
+            pub fn to_bits(self) -> $struct<$bits> {
+                $struct {
+                    $( $field: self.$field.to_bits() ), +
+                }
+            }
+
+            /// Constructs a vector from the raw bits of each field.
+            #[inline]
+            pub fn from_bits(bits: $struct<$bits>) -> Self {
+                Self {
+                    $( $field: <$type>::from_bits(bits.$field) ), +
+                }
+            }
+
+            /// Compares the vectors lexicographically, using the total order of each field's type.
+            pub fn total_cmp(&self, other: &Self) -> Ordering {
+                $(
+                    match self.$field.total_cmp(&other.$field) {
+                        Ordering::Equal => {  },
+                        ordering => return ordering,
+                    }
+                ) +
+
+                Ordering::Equal
+            }
+
+            /// Feeds the raw bits of each field within the vector into `state`.
+            #[inline]
+            pub fn hash_bits<H: Hasher>(&self, state: &mut H) {
+                self.to_bits().hash(state);
+            }
+        }
+
+        impl PartialEq for TotalOrd<$struct<$type>> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0.to_bits() == other.0.to_bits()
+            }
+        }
+
+        impl Eq for TotalOrd<$struct<$type>> {  }
+
+        impl PartialOrd for TotalOrd<$struct<$type>> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for TotalOrd<$struct<$type>> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for TotalOrd<$struct<$type>> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash_bits(state);
+            }
+        }
+
+        impl From<$struct<$type>> for TotalOrd<$struct<$type>> {
+            #[inline]
+            fn from(vector: $struct<$type>) -> Self {
+                Self(vector)
+            }
+        }
+    };
+}
+
+
+impl_total_ord!(Vector2 { x, y }, f32: u32, f64: u64);
+impl_total_ord!(Vector3 { x, y, z }, f32: u32, f64: u64);
+impl_total_ord!(Vector4 { x, y, z, w }, f32: u32, f64: u64);
+impl_total_ord!(Vector3A { x, y, z }, f32: u32, f64: u64);
+impl_total_ord!(Vector4A { x, y, z, w }, f32: u32, f64: u64);
\ No newline at end of file